@@ -1,11 +1,12 @@
+use advent_rust_lib::solution::{Params, Solution, SolutionResult};
+
 pub fn digit<T: AsRef<str>>(s: T) -> Option<u32> {
     let s = s.as_ref();
     let mut it = s.bytes();
 
     let first = (it.find(|b| b.is_ascii_digit())? - 0x30) as u32;
     let second = it
-        .filter(|b| b.is_ascii_digit())
-        .last()
+        .rfind(|b| b.is_ascii_digit())
         .map(|x| (x - 0x30) as u32)
         .unwrap_or(first);
 
@@ -74,6 +75,41 @@ pub fn text_to_digit_value_ordered<T: AsRef<str>>(s: T) -> String {
         .replace("nine", "9")
 }
 
+pub struct Day1;
+
+impl Solution for Day1 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn part_1(&self, input: &str, _params: &Params) -> SolutionResult {
+        let sum: u32 = input
+            .lines()
+            .map(digit)
+            .collect::<Option<Vec<_>>>()
+            .ok_or("Line without a digit")?
+            .into_iter()
+            .sum();
+        Ok(sum.to_string())
+    }
+
+    fn part_2(&self, input: &str, _params: &Params) -> SolutionResult {
+        let sum: u32 = input
+            .lines()
+            .map(text_to_digit)
+            .map(digit)
+            .collect::<Option<Vec<_>>>()
+            .ok_or("Line without a digit")?
+            .into_iter()
+            .sum();
+        Ok(sum.to_string())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
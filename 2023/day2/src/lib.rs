@@ -1,5 +1,6 @@
 use std::str::FromStr;
 
+use advent_rust_lib::solution::{Params, Solution, SolutionResult};
 use anyhow::{anyhow, bail};
 use derive_getters::Getters;
 use itertools::Itertools;
@@ -103,6 +104,41 @@ impl Game {
     }
 }
 
+fn games(input: &str) -> anyhow::Result<Vec<Game>> {
+    input.lines().map(Game::from_str).collect()
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        2
+    }
+
+    fn part_1(&self, input: &str, _params: &Params) -> SolutionResult {
+        let conditions = [(Color::Red, 12), (Color::Green, 13), (Color::Blue, 14)];
+        let sum: u32 = games(input)?
+            .iter()
+            .filter(|game| game.mins_within(&conditions))
+            .map(|game| *game.id())
+            .sum();
+        Ok(sum.to_string())
+    }
+
+    fn part_2(&self, input: &str, _params: &Params) -> SolutionResult {
+        let sum: u32 = games(input)?
+            .iter()
+            .map(|game| game.mins())
+            .map(|out_vec| out_vec.into_iter().product::<u32>())
+            .sum();
+        Ok(sum.to_string())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
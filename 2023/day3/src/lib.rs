@@ -1,5 +1,6 @@
 use std::collections::HashSet;
 
+use advent_rust_lib::solution::{Params, Solution, SolutionResult};
 use derive_getters::Getters;
 use itertools::Itertools;
 
@@ -103,7 +104,7 @@ impl EngineEntries {
                     .map(|gear| (gear, cand.clone()))
             })
             .sorted_by(|(lhs, _), (rhs, _)| lhs.partial_cmp(rhs).unwrap())
-            .group_by(|(gear, _)| *gear)
+            .chunk_by(|(gear, _)| *gear)
             .into_iter()
             .map(|(_, group)| group)
             .filter_map(|group| {
@@ -125,6 +126,28 @@ impl EngineEntries {
     }
 }
 
+pub struct Day3;
+
+impl Solution for Day3 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        3
+    }
+
+    fn part_1(&self, input: &str, _params: &Params) -> SolutionResult {
+        let entries = EngineEntries::from_iter(input.lines());
+        Ok(entries.part_numbers().sum::<u32>().to_string())
+    }
+
+    fn part_2(&self, input: &str, _params: &Params) -> SolutionResult {
+        let entries = EngineEntries::from_iter(input.lines());
+        Ok(entries.gears().into_iter().sum::<u32>().to_string())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
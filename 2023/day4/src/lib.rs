@@ -2,6 +2,7 @@ use std::collections::HashSet;
 use std::num::ParseIntError;
 use std::str::FromStr;
 
+use advent_rust_lib::solution::{Params, Solution, SolutionResult};
 use anyhow::anyhow;
 use anyhow::bail;
 use itertools::Itertools;
@@ -82,6 +83,34 @@ impl Card {
     }
 }
 
+fn cards(input: &str) -> anyhow::Result<Vec<Card>> {
+    input.lines().map(Card::from_str).collect()
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        4
+    }
+
+    fn part_1(&self, input: &str, _params: &Params) -> SolutionResult {
+        let sum: usize = cards(input)?
+            .into_iter()
+            .map(|card| card.match_points())
+            .sum();
+        Ok(sum.to_string())
+    }
+
+    fn part_2(&self, input: &str, _params: &Params) -> SolutionResult {
+        Ok(Card::total_scratchcards(cards(input)?).to_string())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,8 +1,9 @@
-use std::{
-    cmp::{max, min},
-    ops::{Deref, Range},
-};
+use std::ops::{Deref, Range};
 
+use advent_rust_lib::{
+    interval::{IntervalSet, OffsetMap},
+    solution::{Params, Solution, SolutionResult},
+};
 use anyhow::anyhow;
 use itertools::Itertools;
 
@@ -12,59 +13,20 @@ pub struct ConvSet {
     source: Range<u64>,
 }
 
-// Returns the range covered by both lhs and rhs
-fn range_overlap(lhs: &Range<u64>, rhs: &Range<u64>) -> Option<Range<u64>> {
-    let start = max(lhs.start, rhs.start);
-    let end = min(lhs.end, rhs.end);
-
-    if start < end {
-        Some(start..end)
-    } else {
-        None
-    }
-}
-
-impl ConvSet {
-    pub fn convert(&self, val: u64) -> Option<u64> {
-        if self.source.contains(&val) {
-            Some(self.dest.end - (self.source.end - val))
-        } else {
-            None
-        }
-    }
-
-    pub fn convert_range(&self, val: &Range<u64>) -> Option<Range<u64>> {
-        let overlap = range_overlap(&self.source, val)?;
-
-        let offset_start = overlap.start - self.source.start;
-        let offset_end = self.source.end - overlap.end;
-
-        Some((self.dest.start + offset_start)..(self.dest.end - offset_end))
-    }
-}
-
 #[derive(Debug, PartialEq, Eq)]
 pub struct ConvMap {
     sets: Vec<ConvSet>,
+    // Values outside every set are unchanged
+    offsets: OffsetMap<u64>,
 }
 
 impl ConvMap {
     pub fn convert(&self, val: u64) -> u64 {
-        self.sets.iter().find_map(|s| s.convert(val)).unwrap_or(val)
+        self.offsets.map(val)
     }
 
-    pub fn convert_range(&self, val: &[Range<u64>]) -> Vec<Range<u64>> {
-        val.iter()
-            .cloned()
-            .flat_map(|input_range| {
-                let outputs = self
-                    .sets
-                    .iter()
-                    .filter_map(|set| set.convert_range(&input_range))
-                    .collect_vec();
-                outputs
-            })
-            .collect()
+    pub fn convert_range(&self, val: &IntervalSet<u64>) -> IntervalSet<u64> {
+        self.offsets.map_set(val)
     }
 }
 
@@ -75,24 +37,9 @@ impl Deref for ConvMap {
     }
 }
 
-fn split_range(to_split: &Range<u64>, splitter: &Range<u64>) -> Vec<Range<u64>> {
-    if let Some(overlap) = range_overlap(to_split, splitter) {
-        vec![to_split.start..overlap.start, overlap.end..to_split.end]
-    } else {
-        vec![to_split.clone()]
-    }
-}
-
-fn split_ranges(ranges: &[Range<u64>], splitter: &Range<u64>) -> Vec<Range<u64>> {
-    ranges
-        .iter()
-        .flat_map(|to_split| split_range(to_split, splitter))
-        .collect()
-}
-
 impl<S: AsRef<str>> FromIterator<S> for ConvMap {
     fn from_iter<T: IntoIterator<Item = S>>(iter: T) -> Self {
-        let mut sets = iter
+        let sets = iter
             .into_iter()
             .map(|x| {
                 let (dest_start, src_start, len) = x
@@ -112,22 +59,12 @@ impl<S: AsRef<str>> FromIterator<S> for ConvMap {
             .map(|set: anyhow::Result<ConvSet>| set.unwrap())
             .collect_vec();
 
-        // Cover all other values, keeping track of discontinuities
-        let mut ranges = vec![u64::min_value()..u64::max_value()];
-
-        sets.iter()
-            .for_each(|s| ranges = split_ranges(&ranges, &s.source));
-        sets.append(
-            &mut ranges
-                .into_iter()
-                .map(|x| ConvSet {
-                    dest: x.clone(),
-                    source: x.clone(),
-                })
-                .collect(),
-        );
+        let mut offsets = OffsetMap::new();
+        for set in &sets {
+            offsets.insert(set.source.clone(), set.dest.start);
+        }
 
-        Self { sets }
+        Self { sets, offsets }
     }
 }
 
@@ -190,11 +127,11 @@ impl Almanac {
 
     pub fn range_locations(&self) -> impl Iterator<Item = Option<u64>> + '_ {
         Self::pairs(&self.seeds).map(|seed_range| {
-            let mut cur_val = vec![seed_range];
+            let mut cur_val = IntervalSet::from_iter([seed_range]);
             self.convs.iter().for_each(|next_conv| {
                 cur_val = next_conv.convert_range(&cur_val);
             });
-            cur_val.into_iter().map(|r| r.start).min()
+            cur_val.min()
         })
     }
 
@@ -202,7 +139,7 @@ impl Almanac {
         item.iter()
             .step_by(2)
             .zip(item.iter().skip(1).step_by(2))
-            .map(|(start, len)| (*start..start + len))
+            .map(|(start, len)| *start..start + len)
     }
 
     pub fn seeds_as_pairs(&mut self) {
@@ -210,6 +147,34 @@ impl Almanac {
     }
 }
 
+pub struct Day5;
+
+impl Solution for Day5 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        5
+    }
+
+    fn part_1(&self, input: &str, _params: &Params) -> SolutionResult {
+        let almanac = Almanac::from_almanac_iter(input.lines())?;
+        let min = almanac.locations().min().ok_or("No seeds")?;
+        Ok(min.to_string())
+    }
+
+    fn part_2(&self, input: &str, _params: &Params) -> SolutionResult {
+        let almanac = Almanac::from_almanac_iter(input.lines())?;
+        let min = almanac
+            .range_locations()
+            .flatten()
+            .min()
+            .ok_or("No seed ranges")?;
+        Ok(min.to_string())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::{
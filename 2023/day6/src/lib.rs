@@ -1,5 +1,6 @@
 use std::cmp;
 
+use advent_rust_lib::solution::{Params, Solution, SolutionResult};
 use anyhow::anyhow;
 
 #[derive(Debug, PartialEq, Eq)]
@@ -84,6 +85,39 @@ impl Race {
     }
 }
 
+fn race_lines(input: &str) -> anyhow::Result<[&str; 2]> {
+    input
+        .lines()
+        .collect::<Vec<_>>()
+        .try_into()
+        .map_err(|_| anyhow!("Expected a time line and a distance line"))
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        6
+    }
+
+    fn part_1(&self, input: &str, _params: &Params) -> SolutionResult {
+        let product: u64 = parse_races(&race_lines(input)?)?
+            .iter()
+            .map(|r| r.num_ways_to_win())
+            .product();
+        Ok(product.to_string())
+    }
+
+    fn part_2(&self, input: &str, _params: &Params) -> SolutionResult {
+        let race = parse_single_race(&race_lines(input)?)?;
+        Ok(race.num_ways_to_win().to_string())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,5 +1,6 @@
 use std::str::FromStr;
 
+use advent_rust_lib::solution::{Params, Solution, SolutionResult};
 use anyhow::{anyhow, bail};
 use derive_getters::Getters;
 use itertools::Itertools;
@@ -45,7 +46,7 @@ pub enum HandType {
 
 impl From<&mut [Card; 5]> for HandType {
     fn from(value: &mut [Card; 5]) -> Self {
-        let pre_groups = value.iter().sorted().group_by(|x| *x);
+        let pre_groups = value.iter().sorted().chunk_by(|x| *x);
         let mut groups: Vec<_> = pre_groups.into_iter().map(|(_, group)| group).collect();
 
         match groups.len() {
@@ -85,7 +86,7 @@ pub enum WildCardHandType {
 impl From<&mut [Card; 5]> for WildCardHandType {
     fn from(value: &mut [Card; 5]) -> Self {
         let value_clone = value.clone();
-        let pre_groups = value_clone.iter().sorted().group_by(|x| *x);
+        let pre_groups = value_clone.iter().sorted().chunk_by(|x| *x);
         let mut groups: Vec<_> = pre_groups
             .into_iter()
             .map(|(key, mut group)| (key, group.by_ref().count()))
@@ -169,6 +170,41 @@ impl<R: for<'a> From<&'a mut [Card; 5]>> FromStr for Hand<R> {
     }
 }
 
+fn winnings<R>(input: &str) -> anyhow::Result<usize>
+where
+    R: for<'a> From<&'a mut [Card; 5]> + Ord,
+{
+    Ok(input
+        .lines()
+        .map(Hand::<R>::from_str)
+        .collect::<anyhow::Result<Vec<_>>>()?
+        .into_iter()
+        .sorted()
+        .enumerate()
+        .map(|(idx, hand)| (idx + 1) * hand.bid())
+        .sum())
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        7
+    }
+
+    fn part_1(&self, input: &str, _params: &Params) -> SolutionResult {
+        Ok(winnings::<HandType>(input)?.to_string())
+    }
+
+    fn part_2(&self, input: &str, _params: &Params) -> SolutionResult {
+        Ok(winnings::<WildCardHandType>(input)?.to_string())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::{
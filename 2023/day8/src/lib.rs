@@ -1,12 +1,12 @@
 use std::{collections::HashMap, ops::Deref, str::FromStr};
 
+use advent_rust_lib::solution::{Params, Solution, SolutionResult};
 use anyhow::{anyhow, bail};
 use itertools::Itertools;
 use num::{
     integer::{lcm, ExtendedGcd},
     Integer,
 };
-use ring_algorithm::is_coprime;
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum Move {
@@ -54,7 +54,7 @@ impl Mappings {
         I: IntoIterator<Item = S>,
         S: AsRef<str>,
     {
-        let mut iter = iter.into_iter().map(|s| {
+        let iter = iter.into_iter().map(|s| {
             let s = s.as_ref();
             let (tag, points) = s
                 .split('=')
@@ -71,7 +71,7 @@ impl Mappings {
             ))
         });
 
-        let mut inner: HashMap<String, [String; 2]> = iter.try_collect()?;
+        let inner: HashMap<String, [String; 2]> = iter.try_collect()?;
 
         Ok(Self { inner })
     }
@@ -130,7 +130,7 @@ impl Mappings {
             cur_pos = next_pos(cur_pos)?;
         }
 
-        Ok((count, &cur_pos))
+        Ok((count, cur_pos))
     }
 
     /// Returns the first overlap as a gcf, if conditions are met
@@ -142,7 +142,7 @@ impl Mappings {
             .zip(z_idx)
             .all(|(cycle_set, z_idx)| cycle_set.len() == 1 && cycle_set[0] == *z_idx)
         {
-            return Some(z_idx.iter().copied().reduce(lcm).unwrap());
+            Some(z_idx.iter().copied().reduce(lcm).unwrap())
         } else {
             None
         }
@@ -178,7 +178,7 @@ impl Mappings {
             .filter(|set| {
                 set.iter()
                     .combinations(2)
-                    .all(|comb| is_coprime(**comb[0], **comb[1]))
+                    .all(|comb| comb[0].gcd(comb[1]) == 1)
             })
             .filter_map(|set| {
                 Self::chinese_remainder_theorem(z_idx.iter().copied(), set.into_iter().copied())
@@ -207,9 +207,6 @@ impl Mappings {
                     cycle_set.push(pattern_offset);
                 }
 
-                for idx in cycle_set.len()..0 {
-                    cycle_set[idx] -= cycle_set[idx - 1]
-                }
                 cycle_set[0] -= initial_offset;
 
                 Ok::<_, anyhow::Error>(cycle_set)
@@ -269,6 +266,35 @@ impl Mappings {
     }
 }
 
+fn parse(input: &str) -> anyhow::Result<(Directions, Mappings)> {
+    let mut lines = input.lines();
+    let dirs = Directions::from_str(lines.next().ok_or(anyhow!("Empty input"))?)?;
+    let maps = Mappings::from_lines(lines.skip(1))?;
+    Ok((dirs, maps))
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        8
+    }
+
+    fn part_1(&self, input: &str, _params: &Params) -> SolutionResult {
+        let (dirs, maps) = parse(input)?;
+        Ok(maps.walk(&dirs)?.to_string())
+    }
+
+    fn part_2(&self, input: &str, _params: &Params) -> SolutionResult {
+        let (dirs, maps) = parse(input)?;
+        Ok(maps.ghost_walk(&dirs)?.to_string())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::{
@@ -6,8 +6,8 @@ use std::{
     str::FromStr,
 };
 
+use aoc_2023_day9::Sequence;
 use criterion::{criterion_group, criterion_main, Criterion};
-use day9::Sequence;
 
 #[cfg(feature = "simd")]
 use criterion::BenchmarkId;
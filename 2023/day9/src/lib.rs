@@ -1,6 +1,7 @@
 #![cfg_attr(feature = "simd", feature(portable_simd))]
-use std::{fmt::Debug, iter::Sum, ops::Deref, str::FromStr};
+use std::{fmt::Debug, iter::Sum, num::ParseIntError, ops::Deref, str::FromStr};
 
+use advent_rust_lib::solution::{Params, Solution, SolutionResult};
 use itertools::Itertools;
 use num::{Integer, Signed};
 
@@ -188,6 +189,32 @@ where
     }
 }
 
+fn sequences(input: &str) -> Result<Vec<Sequence<i64>>, ParseIntError> {
+    input.lines().map(Sequence::from_str).collect()
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        9
+    }
+
+    fn part_1(&self, input: &str, _params: &Params) -> SolutionResult {
+        let sum: i64 = sequences(input)?.iter().map(|s| s.next()).sum();
+        Ok(sum.to_string())
+    }
+
+    fn part_2(&self, input: &str, _params: &Params) -> SolutionResult {
+        let sum: i64 = sequences(input)?.iter().map(|s| s.prev()).sum();
+        Ok(sum.to_string())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::{
@@ -1,20 +1,6 @@
-use std::{
-    fs::File,
-    io::{BufRead, BufReader},
-    str::FromStr,
-};
-
-use day9::Sequence;
+use advent_rust_lib::solution::run_main;
+use aoc_2023_day9::Day9;
 
 fn main() {
-    let seq: Vec<_> = BufReader::new(File::open("input").unwrap())
-        .lines()
-        .map(|line| Sequence::<i64>::from_str(&line.unwrap()).unwrap())
-        .collect();
-
-    let part1: i64 = seq.iter().map(|s| s.next()).sum();
-    println!("{part1}");
-
-    let part2: i64 = seq.iter().map(|s| s.prev()).sum();
-    println!("{part2}");
+    run_main(&Day9);
 }
@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+
+use advent_rust_lib::solution::{Params, Solution, SolutionResult};
+use itertools::Itertools;
+
+/// Returns the sorted (left, right) lists.
+fn lists(input: &str) -> (Vec<usize>, Vec<usize>) {
+    let (mut left, mut right): (Vec<_>, Vec<_>) = input
+        .lines()
+        .map(|line| {
+            let mut line = line.split_whitespace();
+            let left = line.next().unwrap();
+            let right = line.next().unwrap();
+            (
+                str::parse::<usize>(left).unwrap(),
+                str::parse::<usize>(right).unwrap(),
+            )
+        })
+        .unzip();
+    left.sort_unstable();
+    right.sort_unstable();
+
+    (left, right)
+}
+
+fn part_1(left: Vec<usize>, right: Vec<usize>) -> usize {
+    let pairs = left.iter().zip(right.iter());
+    pairs.map(|(l, r)| l.abs_diff(*r)).sum()
+}
+
+fn part_2(left: Vec<usize>, right: Vec<usize>) -> usize {
+    let left_occurs = left.into_iter().chunk_by(|x| *x);
+    let left_occurs = left_occurs.into_iter().map(|(key, group)| ListOccurrence {
+        num: key,
+        repeats: group.count(),
+    });
+    let right_occurs: HashMap<usize, usize> = right
+        .into_iter()
+        .chunk_by(|x| *x)
+        .into_iter()
+        .map(|(key, group)| (key, group.count()))
+        .collect();
+
+    let mut sum = 0;
+    for left_inst in left_occurs {
+        if let Some(right_inst) = right_occurs.get(&left_inst.num) {
+            sum += left_inst.apply(*right_inst);
+        }
+    }
+    sum
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    fn year(&self) -> u16 {
+        2024
+    }
+
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn part_1(&self, input: &str, _params: &Params) -> SolutionResult {
+        let (left, right) = lists(input);
+        Ok(part_1(left, right).to_string())
+    }
+
+    fn part_2(&self, input: &str, _params: &Params) -> SolutionResult {
+        let (left, right) = lists(input);
+        Ok(part_2(left, right).to_string())
+    }
+}
+
+#[derive(Debug)]
+struct ListOccurrence {
+    pub num: usize,
+    pub repeats: usize,
+}
+
+impl ListOccurrence {
+    pub fn apply(&self, count: usize) -> usize {
+        self.num * self.repeats * count
+    }
+}
@@ -0,0 +1,138 @@
+use advent_rust_lib::{
+    grid::{Grid, Pos2D, RectangleGrid},
+    search::bfs,
+    solution::{Params, Solution, SolutionError, SolutionResult},
+};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    fn year(&self) -> u16 {
+        2024
+    }
+
+    fn day(&self) -> u8 {
+        10
+    }
+
+    fn part_1(&self, input: &str, _params: &Params) -> SolutionResult {
+        let trail = Trail::from_input(input)?;
+        Ok(trail.num_unique_paths().to_string())
+    }
+
+    fn part_2(&self, input: &str, _params: &Params) -> SolutionResult {
+        let trail = Trail::from_input(input)?;
+        Ok(trail.num_paths().to_string())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TrailLoc {
+    pub height: u8,
+}
+
+impl TrailLoc {
+    pub fn new(height: Option<u8>) -> Option<Self> {
+        if let Some(height) = height {
+            (height < 10).then_some(Self { height })
+        } else {
+            Some(Self { height: 10 })
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Trail {
+    grid: RectangleGrid<TrailLoc>,
+}
+
+impl Trail {
+    pub fn from_input(input: &str) -> Result<Self, SolutionError> {
+        let rows = input
+            .lines()
+            .map(|line| {
+                line.chars()
+                    .map(|x| TrailLoc::new(char::to_digit(x, 10).map(|y| y as u8)))
+                    .collect::<Option<Vec<_>>>()
+            })
+            .collect::<Option<Vec<_>>>()
+            .ok_or("Non-digit trail height")?;
+
+        Ok(Self {
+            grid: RectangleGrid::try_from_iter_strict(rows)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct ValuedLoc {
+    pos: Pos2D,
+    count: u64,
+}
+
+impl Trail {
+    fn surrounding_valid(&self, point: Pos2D, height: u8) -> impl Iterator<Item = Pos2D> + use<'_> {
+        self.grid
+            .neighbors4(point)
+            .filter(move |next_point| self.height(*next_point) == height)
+    }
+
+    pub fn num_paths(&self) -> u64 {
+        let mut endpoints: Vec<_> = self
+            .grid
+            .positions()
+            .filter(|point| self.height(*point) == 9)
+            .map(|pos| ValuedLoc { pos, count: 1 })
+            .collect();
+
+        for height in (0..9).rev() {
+            // Collect endpoints into a new vector
+            let mut new_endpoints: Vec<ValuedLoc> = Vec::with_capacity(endpoints.len() * 4);
+
+            // Generate all valid endpoints, draining the existing vector directly
+            let new_endpoint_iter = std::mem::take(&mut endpoints).into_iter().flat_map(|loc| {
+                self.surrounding_valid(loc.pos, height)
+                    .map(move |next_point| ValuedLoc {
+                        pos: next_point,
+                        count: loc.count,
+                    })
+            });
+
+            // Combine converging endpoints
+            for endpoint in new_endpoint_iter {
+                match new_endpoints.binary_search_by_key(&endpoint.pos, |x| x.pos) {
+                    Ok(idx) => new_endpoints[idx].count += endpoint.count,
+                    Err(idx) => new_endpoints.insert(idx, endpoint),
+                }
+            }
+
+            // Assign with the newly generated values
+            endpoints = new_endpoints;
+        }
+
+        // Sum all values at zero
+        endpoints.into_iter().map(|loc| loc.count).sum()
+    }
+
+    fn height(&self, point: Pos2D) -> u8 {
+        self.grid.at(point).map_or(10, |loc| loc.height)
+    }
+
+    pub fn num_unique_paths(&self) -> usize {
+        self.grid
+            .positions()
+            .filter(|point| self.height(*point) == 0)
+            .map(|trailhead| {
+                // With no goal the search reaches every position it can
+                bfs(
+                    trailhead,
+                    |point| self.surrounding_valid(*point, self.height(*point) + 1),
+                    |_| false,
+                )
+                .costs()
+                .filter(|(point, _)| self.height(**point) == 9)
+                .count()
+            })
+            .sum()
+    }
+}
@@ -1,12 +1,15 @@
 #!/usr/bin/env bash
 
-find target/release/ -maxdepth 1 -type f ! -name '.*' ! -name '*.d' |
-	awk '{ print $0 " input 75"}' |
+cargo build --release --bins
+
+find src/bin/ -name '*.rs' -printf '%f\n' |
+	awk '{ sub(/\.rs$/, ""); print "../../target/release/" $0 " input 75"}' |
 	tr '\n' '\0' |
 	xargs -0 hyperfine -N -w 1000 --export-markdown release-bench.md
 
 # If you also want full perf runs
-#find target/full-perf/ -maxdepth 1 -type f ! -name '.*' ! -name '*.d' |
-#	awk '{ print $0 " input 75"}' |
+#cargo build --profile full-perf --bins
+#find src/bin/ -name '*.rs' -printf '%f\n' |
+#	awk '{ sub(/\.rs$/, ""); print "../../target/full-perf/" $0 " input 75"}' |
 #	tr '\n' '\0' |
 #	xargs -0 hyperfine -N -w 1000 --export-markdown full-perf-bench.md
@@ -1,10 +1,6 @@
-use std::{
-    collections::{HashMap, HashSet},
-    env::args,
-    num::ParseIntError,
-};
+use std::{env::args, num::ParseIntError};
 
-use advent_rust_lib::read::filtered_input;
+use advent_rust_lib::{memo::Memo, read::filtered_input};
 
 fn main() {
     let stones = StoneCollection::from_line(filtered_input(&[1]).next().unwrap()).unwrap();
@@ -51,7 +47,7 @@ impl Stone {
 #[derive(Debug, Clone)]
 pub struct StoneCollection {
     arr: Vec<Stone>,
-    res_cache: HashMap<u64, (u64, u64)>,
+    res_cache: Memo<u64, (u64, u64)>,
 }
 
 impl StoneCollection {
@@ -69,7 +65,7 @@ impl StoneCollection {
 
         let mut this = Self {
             arr,
-            res_cache: HashMap::new(),
+            res_cache: Memo::new(),
         };
         this.condense();
         Ok(this)
@@ -144,10 +140,10 @@ impl StoneCollection {
                 // Copy out to another stone
                 let mut other_stone = *stone;
 
-                (stone.value, other_stone.value) = *self
-                    .res_cache
-                    .entry(stone.value)
-                    .or_insert_with(|| Self::split_digits_at(stone.value, num_digits / 2));
+                (stone.value, other_stone.value) =
+                    self.res_cache.get_or_insert_with(stone.value, |value| {
+                        Self::split_digits_at(*value, num_digits / 2)
+                    });
 
                 // Store the new stone for later insert
                 new_entries.push(other_stone);
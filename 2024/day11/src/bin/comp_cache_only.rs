@@ -1,6 +1,6 @@
-use std::{collections::HashMap, env::args, num::ParseIntError};
+use std::{env::args, num::ParseIntError};
 
-use advent_rust_lib::read::filtered_input;
+use advent_rust_lib::{memo::Memo, read::filtered_input};
 
 fn main() {
     let stones = StoneCollection::from_line(filtered_input(&[1]).next().unwrap()).unwrap();
@@ -29,7 +29,7 @@ pub enum ChildStones {
 
 #[derive(Debug, Clone)]
 pub struct StoneCollection {
-    comp_cache: HashMap<u64, ChildStones>,
+    comp_cache: Memo<u64, ChildStones>,
     counts: Vec<(u64, u64)>,
 }
 
@@ -47,14 +47,14 @@ impl StoneCollection {
             .collect();
 
         // Initialize with the zero special case
-        let comp_cache: HashMap<_, _> = [(0, ChildStones::One(1))].into();
+        let comp_cache = Memo::with_cache([(0, ChildStones::One(1))].into());
 
         Ok(Self { comp_cache, counts })
     }
 
     pub fn gen_children(value: u64) -> ChildStones {
         let num_digits = Self::num_digits(value);
-        if (num_digits % 2) == 0 {
+        if num_digits.is_multiple_of(2) {
             let (child0, child1) = Self::split_digits_at(value, num_digits / 2);
 
             // Store the new stone for later insert
@@ -117,14 +117,13 @@ impl StoneCollection {
         for (idx, count) in &mut self.counts[..] {
             let children = self
                 .comp_cache
-                .entry(*idx)
-                .or_insert_with(|| Self::gen_children(*idx));
+                .get_or_insert_with(*idx, |idx| Self::gen_children(*idx));
 
             match children {
-                ChildStones::One(child_idx) => *idx = *child_idx,
+                ChildStones::One(child_idx) => *idx = child_idx,
                 ChildStones::Two([child_idx_0, child_idx_1]) => {
-                    *idx = *child_idx_0;
-                    leftovers.push((*child_idx_1, *count));
+                    *idx = child_idx_0;
+                    leftovers.push((child_idx_1, *count));
                 }
             }
         }
@@ -138,7 +137,7 @@ impl StoneCollection {
         let mut sum = 0;
 
         for (idx, count) in &self.counts[..] {
-            if let Some(children) = self.comp_cache.get(idx) {
+            if let Some(children) = self.comp_cache.cache().get(idx) {
                 match children {
                     ChildStones::One(_) => sum += count,
                     ChildStones::Two(_) => {
@@ -1,6 +1,6 @@
 use std::{collections::HashMap, env::args, num::ParseIntError};
 
-use advent_rust_lib::read::filtered_input;
+use advent_rust_lib::{memo::Memo, read::filtered_input};
 
 fn main() {
     let stones = StoneCollection::from_line(filtered_input(&[1]).next().unwrap()).unwrap();
@@ -33,7 +33,7 @@ pub enum ChildStones {
 
 #[derive(Debug, Clone)]
 pub struct StoneCollection {
-    comp_cache: HashMap<u64, ChildStones>,
+    comp_cache: Memo<u64, ChildStones>,
     counts: HashMap<u64, u64>,
 }
 
@@ -51,14 +51,14 @@ impl StoneCollection {
             .collect();
 
         // Initialize with the zero special case
-        let comp_cache: HashMap<_, _> = [(0, ChildStones::One(1))].into();
+        let comp_cache = Memo::with_cache([(0, ChildStones::One(1))].into());
 
         Ok(Self { comp_cache, counts })
     }
 
     pub fn gen_children(value: u64) -> ChildStones {
         let num_digits = Self::num_digits(value);
-        if (num_digits % 2) == 0 {
+        if num_digits.is_multiple_of(2) {
             let (child0, child1) = Self::split_digits_at(value, num_digits / 2);
 
             // Store the new stone for later insert
@@ -101,14 +101,13 @@ impl StoneCollection {
         for (idx, count) in prev_counts {
             let children = self
                 .comp_cache
-                .entry(idx)
-                .or_insert_with(|| Self::gen_children(idx));
+                .get_or_insert_with(idx, |idx| Self::gen_children(*idx));
 
             match children {
-                ChildStones::One(child_idx) => *self.counts.entry(*child_idx).or_insert(0) += count,
+                ChildStones::One(child_idx) => *self.counts.entry(child_idx).or_insert(0) += count,
                 ChildStones::Two([child_idx_0, child_idx_1]) => {
-                    *self.counts.entry(*child_idx_0).or_insert(0) += count;
-                    *self.counts.entry(*child_idx_1).or_insert(0) += count;
+                    *self.counts.entry(child_idx_0).or_insert(0) += count;
+                    *self.counts.entry(child_idx_1).or_insert(0) += count;
                 }
             }
         }
@@ -126,7 +125,7 @@ impl StoneCollection {
             .collect();
 
         for (idx, count) in prev_counts {
-            if let Some(children) = self.comp_cache.get(&idx) {
+            if let Some(children) = self.comp_cache.cache().get(&idx) {
                 match children {
                     ChildStones::One(_) => sum += count,
                     ChildStones::Two(_) => {
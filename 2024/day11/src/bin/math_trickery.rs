@@ -1,4 +1,4 @@
-use std::{env::args, num::ParseIntError, u8};
+use std::{env::args, num::ParseIntError};
 
 use advent_rust_lib::read::filtered_input;
 
@@ -54,7 +54,7 @@ impl Stone {
         let even_digits = (value != 0)
             .then(|| {
                 let num_digits = num_digits(value);
-                ((num_digits % 2) == 0).then_some(num_digits)
+                num_digits.is_multiple_of(2).then_some(num_digits)
             })
             .flatten();
 
@@ -74,13 +74,13 @@ impl Stone {
     pub fn update_value(&mut self, new_value: u64) {
         self.value *= new_value;
         let num_digits = num_digits(self.value);
-        self.even_digits = ((num_digits % 2) == 0).then_some(num_digits);
+        self.even_digits = num_digits.is_multiple_of(2).then_some(num_digits);
     }
 
     /// Updates even digits.
     pub fn set_digits(&mut self) {
         let num_digits = num_digits(self.value);
-        self.even_digits = ((num_digits % 2) == 0).then_some(num_digits);
+        self.even_digits = num_digits.is_multiple_of(2).then_some(num_digits);
     }
 }
 
@@ -1,4 +1,4 @@
-use std::{env::args, num::ParseIntError, u8};
+use std::{env::args, num::ParseIntError};
 
 use advent_rust_lib::read::filtered_input;
 
@@ -52,7 +52,7 @@ impl NumDigitsEven {
             Self(0)
         } else {
             let num = num_digits(val);
-            if (num % 2) == 0 {
+            if num.is_multiple_of(2) {
                 Self(num)
             } else {
                 Self(0)
@@ -73,7 +73,7 @@ impl NumDigitsEven {
     }
 
     pub fn from_halve(halve: u8) -> Self {
-        if (halve % 2) == 0 {
+        if halve.is_multiple_of(2) {
             Self(halve)
         } else {
             Self(0)
@@ -105,7 +105,7 @@ impl StoneCollection {
     ///
     /// Uses base 10 for digits.
     fn split_digits_in_half(val: u64) -> (u64, u64) {
-        let half = (val.ilog10() + 1) / 2;
+        let half = val.ilog10().div_ceil(2);
         let divisor = 10_u64.pow(half);
 
         (val / divisor, val % divisor)
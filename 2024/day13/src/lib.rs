@@ -0,0 +1,140 @@
+use advent_rust_lib::{
+    parse::{sections, ParseError, Section},
+    solution::{Params, Solution, SolutionResult},
+};
+use num::Integer;
+
+fn machines(input: &str) -> Result<Vec<ClawMachine>, ParseError> {
+    sections(input).map(ClawMachine::from_section).collect()
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    fn year(&self) -> u16 {
+        2024
+    }
+
+    fn day(&self) -> u8 {
+        13
+    }
+
+    fn part_1(&self, input: &str, _params: &Params) -> SolutionResult {
+        Ok(part_1(&machines(input)?).to_string())
+    }
+
+    fn part_2(&self, input: &str, _params: &Params) -> SolutionResult {
+        Ok(part_2(&machines(input)?).to_string())
+    }
+}
+
+fn part_1(machines: &[ClawMachine]) -> u64 {
+    machines
+        .iter()
+        .map(|machine| machine.min_token_presses())
+        .sum()
+}
+
+fn part_2(machines: &[ClawMachine]) -> u64 {
+    machines
+        .iter()
+        .cloned()
+        .map(|mut machine| {
+            machine.prize.0 += 10000000000000;
+            machine.prize.1 += 10000000000000;
+
+            machine.min_token_presses()
+        })
+        .sum()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct ClawMachine {
+    button_a: (i64, i64),
+    button_b: (i64, i64),
+    prize: (i64, i64),
+}
+
+impl ClawMachine {
+    /// Parses one "Button A", "Button B", "Prize" block.
+    fn from_section(section: Section) -> Result<Self, ParseError> {
+        let pair = |label| {
+            let [x, y] = section.labeled_section(label)?.int_array()?;
+            Ok::<_, ParseError>((x, y))
+        };
+
+        Ok(Self {
+            button_a: pair("Button A")?,
+            button_b: pair("Button B")?,
+            prize: pair("Prize")?,
+        })
+    }
+}
+
+impl ClawMachine {
+    /// Look for an integer solution to a 2x2 system of equations.
+    /// A non-integer solution means no number of presses is possible.
+    ///
+    /// Returns Option<(lhs_coeff, rhs_coeff)>
+    fn solve_two_by_two(
+        lhs: (i64, i64),
+        rhs: (i64, i64),
+        target: (i64, i64),
+    ) -> Option<(u64, u64)> {
+        // x * x_coeff = y * y_coeff
+        let lhs_lcm = lhs.0.lcm(&lhs.1);
+        let lhs_x_coeff = -(lhs_lcm / lhs.0);
+        let lhs_y_coeff = lhs_lcm / lhs.1;
+
+        // [lhs * lhs.0 + rhs * rhs.0] = target.0
+        // [lhs * lhs.1 + rhs * rhs.1] = target.1
+        //
+        // Eliminate lhs by multiplying coefficients
+        //
+        // [rhs * rhs.0 * lhs_x_coeff] = target.0 * lhs_x_coeff
+        // [rhs * rhs.1 * lhs_y_coeff] = target.1 * lhs_y_coeff
+        //
+        // Consolidate to one equation
+        // rhs * [(rhs.0 * lhs_x_coeff) + (rhs.1 * lhs_y_coeff)] = ...
+        let rhs_x = rhs.0 * lhs_x_coeff;
+        let rhs_y = rhs.1 * lhs_y_coeff;
+        let rhs_total = rhs_x + rhs_y;
+
+        // ... = (target.0 * lhs_x_coeff) + (target.1 * lhs_y_coeff)
+        let target_x = target.0 * lhs_x_coeff;
+        let target_y = target.1 * lhs_y_coeff;
+        let target_total = target_x + target_y;
+
+        // Finish solve for rhs
+        let b = target_total / rhs_total;
+        // B integer check, no extra cost to modulo on most architectures
+        let b_is_integer = (target_total % (rhs_total)) == 0;
+
+        // Arbitrarily use the top equation to solve for lhs
+        let lhs_top = target.0 - (rhs.0 * b);
+        let a = lhs_top / lhs.0;
+        // A integer check, no extra cost to modulo on most architectures
+        let a_is_integer = (lhs_top % lhs.0) == 0;
+
+        // Only integer coefficients are valid
+        if b_is_integer && a_is_integer {
+            let a = a as u64;
+            let b = b as u64;
+            Some((a, b))
+        } else {
+            None
+        }
+    }
+
+    /// Minimum number of token presses to reach the prize.
+    ///
+    /// Zero indicates either a == prize, b == prize, or no combination of
+    /// presses reaches the prize.
+    pub fn min_token_presses(&self) -> u64 {
+        // Only ever one solution to the system of equations.
+        let a_solve = Self::solve_two_by_two(self.button_a, self.button_b, self.prize)
+            .map(|solve| (solve.0 * 3) + solve.1);
+
+        a_solve.unwrap_or(0)
+    }
+}
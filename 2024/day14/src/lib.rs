@@ -0,0 +1,175 @@
+use std::{
+    cmp::Ordering,
+    collections::HashSet,
+    fs::{create_dir_all, File},
+    io::BufWriter,
+    num::ParseIntError,
+    path::Path,
+    sync::LazyLock,
+};
+
+use advent_rust_lib::{
+    grid::Pos2D,
+    grid_ext::Pos2DExt,
+    render::{Canvas, RenderError, Style},
+    solution::{Params, Solution, SolutionError, SolutionResult},
+};
+use regex::Regex;
+use thiserror::Error;
+
+/// Returns (robots, width, height).
+///
+/// The first line of input is the "width,height" of the area.
+fn parse(input: &str) -> Result<(Vec<Robot>, i64, i64), SolutionError> {
+    let mut input = input.lines();
+
+    let (width, height): (i64, i64) = {
+        let next_input = input.next().ok_or("Missing dimensions line")?;
+        let (width, height) = next_input
+            .split_once(',')
+            .ok_or("Dimensions must be \"width,height\"")?;
+        (str::parse(width)?, str::parse(height)?)
+    };
+
+    let robots: Vec<_> = input
+        .map(Robot::from_input_line)
+        .collect::<Result<_, _>>()?;
+
+    Ok((robots, width, height))
+}
+
+/// Pass `--frames <dir>` to write an image of every step in part 2.
+pub struct Day14;
+
+impl Solution for Day14 {
+    fn year(&self) -> u16 {
+        2024
+    }
+
+    fn day(&self) -> u8 {
+        14
+    }
+
+    fn part_1(&self, input: &str, _params: &Params) -> SolutionResult {
+        let (robots, width, height) = parse(input)?;
+        Ok(part_1(&robots, width, height).to_string())
+    }
+
+    fn part_2(&self, input: &str, params: &Params) -> SolutionResult {
+        let (robots, width, height) = parse(input)?;
+        let frames: Option<String> = params.get_opt("frames")?;
+        Ok(part_2(&robots, width, height, frames.as_deref().map(Path::new))?.to_string())
+    }
+}
+
+fn part_1(robots: &[Robot], width: i64, height: i64) -> i64 {
+    let (half_width, half_height) = (width / 2, height / 2);
+
+    let mut quad_sums = [0; 4];
+
+    robots
+        .iter()
+        .map(|robot| robot.step(100, width, height))
+        .for_each(|(x, y)| {
+            let mut idx = match x.cmp(&half_width) {
+                Ordering::Less => 0,
+                Ordering::Greater => 1,
+                Ordering::Equal => return,
+            };
+
+            match y.cmp(&half_height) {
+                Ordering::Greater => idx += 2,
+                Ordering::Less => (),
+                Ordering::Equal => return,
+            };
+
+            quad_sums[idx] += 1;
+        });
+
+    quad_sums.iter().product::<i64>()
+}
+
+/// Returns the number of steps before the robots repeat a layout.
+fn part_2(
+    robots: &[Robot],
+    width: i64,
+    height: i64,
+    frames: Option<&Path>,
+) -> Result<i64, RenderError> {
+    let mut maps = HashSet::new();
+
+    for num_steps in 0..i64::MAX {
+        let new_map: Vec<_> = robots
+            .iter()
+            .map(|robot| robot.step(num_steps, width, height))
+            .map(|(x, y)| (x as u64, y as u64))
+            .collect();
+
+        if let Some(frames) = frames {
+            create_dir_all(frames)?;
+            let writer = BufWriter::new(File::create(frames.join(num_steps.to_string() + ".png"))?);
+            draw_map(&new_map, width, height).write_png(writer, 1)?;
+        }
+
+        let insert_status = maps.insert(new_map);
+
+        if !insert_status {
+            return Ok(num_steps);
+        }
+    }
+
+    Ok(i64::MAX)
+}
+
+/// White robots on a black background.
+fn draw_map(coordinates: &[(u64, u64)], width: i64, height: i64) -> Canvas {
+    let mut canvas = Canvas::new(width as usize, height as usize);
+    for (x, y) in coordinates {
+        canvas.set(Pos2D::new(*x as usize, *y as usize), Style::new('#'));
+    }
+    canvas
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Robot {
+    pos: (i64, i64),
+    change: (i64, i64),
+}
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum RobotErr {
+    #[error("No position entry")]
+    MissingPosition,
+    #[error("No change entry")]
+    MissingChange,
+    #[error("{0}")]
+    ParseIntError(#[from] ParseIntError),
+}
+
+impl Robot {
+    pub fn from_input_line<S: AsRef<str>>(line: S) -> Result<Self, RobotErr> {
+        static POSITION: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new(r#".=((-|\d)+),((-|\d)+)"#).unwrap());
+
+        let mut position_entries = POSITION.captures_iter(line.as_ref()).map(|cap| {
+            let (_, [x, _, y, _]) = cap.extract();
+            Ok::<_, ParseIntError>((str::parse(x)?, str::parse(y)?))
+        });
+
+        let pos = position_entries.next().ok_or(RobotErr::MissingPosition)??;
+        let change = position_entries.next().ok_or(RobotErr::MissingChange)??;
+
+        Ok(Self { pos, change })
+    }
+
+    /// Returns final position (x, y) after stepping `times` with wraparound.
+    pub fn step(self, times: i64, max_width: i64, max_height: i64) -> (i64, i64) {
+        let end = Pos2DExt::new(
+            self.pos.0 + (self.change.0 * times),
+            self.pos.1 + (self.change.1 * times),
+        )
+        .wrap_within(Pos2DExt::new(max_width, max_height));
+
+        (end.x, end.y)
+    }
+}
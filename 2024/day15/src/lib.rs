@@ -0,0 +1,438 @@
+use advent_rust_lib::{
+    direction::Direction,
+    grid::{GridCell, GridEntry, Pos2D, RectangleGrid},
+    iter::ArrayIter,
+    parse::sections,
+    solution::{Params, Solution, SolutionError, SolutionResult},
+};
+
+/// Returns (map, robot directions).
+fn parse(input: &str) -> Result<(Map, Vec<Direction>), SolutionError> {
+    let mut input = sections(input);
+
+    let map = Map::from_input(input.next().ok_or("Missing warehouse map")?.text())?;
+
+    let directions: Vec<_> = input
+        .flat_map(|section| section.text().chars().flat_map(parse_dir_arrow))
+        .collect();
+
+    Ok((map, directions))
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    fn year(&self) -> u16 {
+        2024
+    }
+
+    fn day(&self) -> u8 {
+        15
+    }
+
+    fn part_1(&self, input: &str, _params: &Params) -> SolutionResult {
+        let (map, directions) = parse(input)?;
+        Ok(part_1(map, &directions).to_string())
+    }
+
+    fn part_2(&self, input: &str, _params: &Params) -> SolutionResult {
+        let (map, directions) = parse(input)?;
+        Ok(part_2(map, &directions).to_string())
+    }
+}
+
+fn part_1(mut map: Map, directions: &[Direction]) -> usize {
+    for dir in directions {
+        map.step(*dir);
+    }
+
+    #[cfg(feature = "print")]
+    {
+        map.print();
+        println!()
+    }
+
+    map.positioned_items()
+        .filter(|entry| *entry.value == Element::Box)
+        .map(|entry| {
+            let pos = entry.position();
+            (pos.y * 100) + pos.x
+        })
+        .sum()
+}
+
+fn part_2(map: Map, directions: &[Direction]) -> usize {
+    let mut map = WideMap::from_regular(map);
+
+    for dir in directions {
+        map.step(*dir);
+
+        #[cfg(debug_assertions)]
+        if !map.validate_boxes() {
+            #[cfg(feature = "print")]
+            {
+                map.print();
+                println!()
+            }
+            panic!("{}", dir);
+        }
+    }
+
+    #[cfg(feature = "print")]
+    {
+        map.print();
+        println!()
+    }
+
+    map.positioned_items()
+        .filter(|entry| *entry.value == WideElement::LeftBox)
+        .map(|entry| {
+            let pos = entry.position();
+            (pos.y * 100) + pos.x
+        })
+        .sum()
+}
+
+// -------------------------------------------------- //
+
+#[derive(Debug, Clone)]
+struct Map {
+    grid: RectangleGrid<Element>,
+    robot: Pos2D,
+}
+
+impl Map {
+    pub fn from_input(input: &str) -> Result<Self, SolutionError> {
+        let (grid, markers) = RectangleGrid::parse_cells(input, &['@'])?;
+
+        Ok(Self {
+            grid,
+            robot: markers.first('@').ok_or("No robot (@) in the map")?,
+        })
+    }
+
+    pub fn step(&mut self, dir: Direction) {
+        if let Some(new_robot) = self.robot.step_dir(dir) {
+            if let Some(grid_entry) = self.grid.get(new_robot).filter(|entry| !entry.is_empty()) {
+                if *grid_entry == Element::Box && self.push_box(dir, new_robot) {
+                    // Box was pushed, robot can move into empty space
+                    self.robot = new_robot;
+                }
+            } else {
+                // No obstacles, robot can move
+                self.robot = new_robot;
+            }
+        }
+    }
+
+    /// Pushes the box at `box_pos` in `dir`, if possible.
+    ///
+    /// Returns true if successful, false otherwise.
+    fn push_box(&mut self, dir: Direction, box_pos: Pos2D) -> bool {
+        if let Some(new_box) = box_pos.step_dir(dir) {
+            if let Some(grid_entry) = self.grid.get(new_box).copied() {
+                let success = match grid_entry {
+                    Element::Wall => false,
+                    Element::Empty => true,
+                    Element::Box => self.push_box(dir, new_box),
+                };
+
+                if success {
+                    // Shift this box to its new position
+                    *self
+                        .grid
+                        .get_mut(new_box)
+                        .expect("already confirmed this location exists") = Element::Box;
+                    *self
+                        .grid
+                        .get_mut(box_pos)
+                        .expect("already confirmed this location exists") = Element::Empty;
+                }
+
+                success
+            } else {
+                // Outside of grid
+                false
+            }
+        } else {
+            // Outside of grid
+            false
+        }
+    }
+
+    pub fn positioned_items(&self) -> impl ArrayIter<GridEntry<'_, Element>> {
+        self.grid.positioned_items()
+    }
+
+    #[cfg(feature = "print")]
+    pub fn print(&self) {
+        print!("{}", self.grid);
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, GridCell)]
+enum Element {
+    // The robot's starting position is also empty
+    #[default]
+    #[cell('.', '@')]
+    Empty,
+    #[cell('#')]
+    Wall,
+    #[cell('O')]
+    Box,
+}
+
+impl Element {
+    fn is_empty(&self) -> bool {
+        *self == Element::Empty
+    }
+}
+
+fn parse_dir_arrow(c: char) -> Option<Direction> {
+    match c {
+        '^' => Some(Direction::Up),
+        'v' => Some(Direction::Down),
+        '<' => Some(Direction::Left),
+        '>' => Some(Direction::Right),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone)]
+struct WideMap {
+    grid: RectangleGrid<WideElement>,
+    robot: Pos2D,
+}
+
+impl WideMap {
+    pub fn from_regular(map: Map) -> Self {
+        let grid_iter = map.grid.lines().map(|line| {
+            line.iter().flat_map(|elem| match elem {
+                Element::Empty => [WideElement::Empty, WideElement::Empty],
+                Element::Wall => [WideElement::Wall, WideElement::Wall],
+                Element::Box => [WideElement::LeftBox, WideElement::RightBox],
+            })
+        });
+        let grid =
+            RectangleGrid::try_from_iter(grid_iter).expect("All X dims are equally increased");
+        let robot = Pos2D::new(map.robot.x * 2, map.robot.y);
+
+        Self { grid, robot }
+    }
+
+    pub fn step(&mut self, dir: Direction) {
+        if let Some(new_robot) = self.robot.step_dir(dir) {
+            if let Some(grid_entry) = self.grid.get(new_robot).filter(|entry| !entry.is_empty()) {
+                if grid_entry.is_box() && self.push_box(dir, new_robot) {
+                    // Box was pushed, robot can move into empty space
+                    self.robot = new_robot;
+                }
+            } else {
+                // No obstacles, robot can move
+                self.robot = new_robot;
+            }
+        }
+    }
+
+    /// Pushes the box at `box_pos` in `dir`, if possible.
+    ///
+    /// Returns true if successful, false otherwise.
+    fn push_box(&mut self, dir: Direction, box_pos: Pos2D) -> bool {
+        match dir {
+            Direction::Left | Direction::Right => {
+                self.push_box_side(dir == Direction::Right, box_pos)
+            }
+            Direction::Up | Direction::Down => {
+                if let Some(item) = self
+                    .grid
+                    .get(box_pos)
+                    .copied()
+                    .filter(|item| !item.is_empty())
+                {
+                    let pos_pair = match item {
+                        WideElement::Empty | WideElement::Wall => {
+                            panic!("Push box should only have been called with a box!")
+                        }
+                        WideElement::LeftBox => {
+                            let other_box = Pos2D::new(box_pos.x + 1, box_pos.y);
+                            [box_pos, other_box]
+                        }
+                        WideElement::RightBox => {
+                            let other_box = Pos2D::new(box_pos.x - 1, box_pos.y);
+                            [other_box, box_pos]
+                        }
+                    };
+
+                    if let Some(mut locs) = self.push_box_vert(dir, pos_pair) {
+                        locs.sort_unstable_by(|lhs, rhs| lhs.y.cmp(&rhs.y).then(lhs.x.cmp(&rhs.x)));
+                        locs.dedup();
+
+                        if dir == Direction::Up {
+                            // Highest y first
+                            for loc in &locs {
+                                let val = std::mem::take(
+                                    self.grid.get_mut(*loc).expect("found element must exist"),
+                                );
+                                let upper = loc.up().expect("must have an upper element");
+                                *self
+                                    .grid
+                                    .get_mut(upper)
+                                    .expect("upper element must be in the grid") = val;
+                            }
+                        } else {
+                            debug_assert_eq!(dir, Direction::Down);
+
+                            // Lowest y first
+                            for loc in locs.iter().rev() {
+                                let val = std::mem::take(
+                                    self.grid.get_mut(*loc).expect("found element must exist"),
+                                );
+                                let lower = loc.down().expect("must have an lower element");
+                                *self
+                                    .grid
+                                    .get_mut(lower)
+                                    .expect("lower element must be in the grid") = val;
+                            }
+                        }
+
+                        true
+                    } else {
+                        false
+                    }
+                } else {
+                    false
+                }
+            }
+        }
+    }
+
+    /// Pushes the box at `box_pos` to the side in `dir`, if possible.
+    ///
+    /// Returns Some(num) for the amount of side shift, if possible.
+    fn push_box_side(&mut self, right: bool, box_pos: Pos2D) -> bool {
+        let next = |pos: Pos2D| if right { pos.right() } else { pos.left() };
+        let mut working_box_pos = box_pos;
+
+        loop {
+            if let Some(new_box) = next(working_box_pos) {
+                if let Some(grid_entry) = self.grid.get(new_box).copied() {
+                    match grid_entry {
+                        WideElement::Wall => return false,
+                        WideElement::Empty => break,
+                        WideElement::LeftBox | WideElement::RightBox => {
+                            working_box_pos = new_box;
+                        }
+                    }
+                } else {
+                    // Outside of grid
+                    return false;
+                }
+            } else {
+                // Outside of grid
+                return false;
+            }
+        }
+
+        // Copy grid to the side
+        let line = self
+            .grid
+            .lines_mut()
+            .nth(box_pos.y)
+            .expect("this function must have been called with a valid box location");
+        if right {
+            let robot_x = box_pos.x - 1;
+            line.copy_within(robot_x..=working_box_pos.x, robot_x + 1);
+            line[robot_x] = WideElement::Empty;
+        } else {
+            let robot_x = box_pos.x + 1;
+            line.copy_within(working_box_pos.x..=robot_x, working_box_pos.x - 1);
+        };
+
+        true
+    }
+
+    /// Pushes the box at `box_pos` vertically in `dir`, if possible.
+    ///
+    /// Returns the sequence of positions if successful.
+    fn push_box_vert(&mut self, dir: Direction, box_poses: [Pos2D; 2]) -> Option<Vec<Pos2D>> {
+        let mut positions = box_poses.to_vec();
+
+        for box_pos in box_poses {
+            if let Some(new_box) = box_pos.step_dir(dir) {
+                if let Some(grid_entry) = self.grid.get(new_box).copied() {
+                    let mut new_positions = match grid_entry {
+                        WideElement::Wall => return None,
+                        WideElement::Empty => vec![],
+                        WideElement::LeftBox => {
+                            let alt_box = Pos2D::new(new_box.x + 1, new_box.y);
+                            self.push_box_vert(dir, [new_box, alt_box])?
+                        }
+                        WideElement::RightBox => {
+                            let alt_box = Pos2D::new(new_box.x - 1, new_box.y);
+                            self.push_box_vert(dir, [alt_box, new_box])?
+                        }
+                    };
+
+                    positions.append(&mut new_positions);
+                } else {
+                    // Outside of grid
+                    return None;
+                }
+            } else {
+                // Outside of grid
+                return None;
+            }
+        }
+
+        Some(positions)
+    }
+
+    pub fn positioned_items(&self) -> impl ArrayIter<GridEntry<'_, WideElement>> {
+        self.grid.positioned_items()
+    }
+
+    #[cfg(feature = "print")]
+    pub fn print(&self) {
+        print!("{}", self.grid);
+    }
+
+    #[cfg(debug_assertions)]
+    pub fn validate_boxes(&self) -> bool {
+        self.grid.lines().all(|line| {
+            let mut line = line.iter();
+            while let Some(lhs) = line.next() {
+                if *lhs == WideElement::LeftBox && line.next() != Some(&WideElement::RightBox) {
+                    return false;
+                }
+
+                if *lhs == WideElement::RightBox {
+                    return false;
+                }
+            }
+            true
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, GridCell)]
+enum WideElement {
+    #[default]
+    #[cell('.')]
+    Empty,
+    #[cell('#')]
+    Wall,
+    #[cell('[')]
+    LeftBox,
+    #[cell(']')]
+    RightBox,
+}
+
+impl WideElement {
+    fn is_empty(&self) -> bool {
+        *self == WideElement::Empty
+    }
+
+    fn is_box(&self) -> bool {
+        [WideElement::LeftBox, WideElement::RightBox].contains(self)
+    }
+}
@@ -0,0 +1,124 @@
+use std::collections::HashSet;
+
+use advent_rust_lib::{
+    direction::Direction,
+    grid::{Pos2D, RectangleGrid},
+    search::{dijkstra, Search},
+    solution::{Params, Solution, SolutionError, SolutionResult},
+};
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    fn year(&self) -> u16 {
+        2024
+    }
+
+    fn day(&self) -> u8 {
+        16
+    }
+
+    fn part_1(&self, input: &str, _params: &Params) -> SolutionResult {
+        let maze = Maze::from_input(input)?;
+        Ok(maze.min_score().ok_or("No path to the end")?.to_string())
+    }
+
+    fn part_2(&self, input: &str, _params: &Params) -> SolutionResult {
+        let maze = Maze::from_input(input)?;
+        Ok(maze.num_tiles_on_best_paths().to_string())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Reindeer {
+    pub facing: Direction,
+    pub pos: Pos2D,
+}
+
+#[derive(Debug)]
+struct Maze {
+    // True when a wall, false otherwise
+    grid: RectangleGrid<bool>,
+    start: Reindeer,
+    end: Pos2D,
+}
+
+impl Maze {
+    pub fn from_input(input: &str) -> Result<Self, SolutionError> {
+        let (grid, markers) = RectangleGrid::parse_chars(input, &['S', 'E'], |c| c == '#')?;
+
+        Ok(Self {
+            grid,
+            start: Reindeer {
+                facing: Direction::Left,
+                pos: markers.first('S').ok_or("No start (S) in the maze")?,
+            },
+            end: markers.first('E').ok_or("No end (E) in the maze")?,
+        })
+    }
+}
+
+const TURN_COST: u64 = 1000;
+const STEP_COST: u64 = 1;
+
+impl Maze {
+    /// Returns the stepped reindeer, if valid
+    fn step_reindeer(&self, reindeer: Reindeer) -> Option<Reindeer> {
+        let new_reindeer = Reindeer {
+            pos: reindeer.pos.step_dir(reindeer.facing)?,
+            facing: reindeer.facing,
+        };
+
+        if !(*self.grid.get(new_reindeer.pos)?) {
+            Some(new_reindeer)
+        } else {
+            None
+        }
+    }
+
+    /// Turning in either direction or stepping forward, with costs.
+    fn moves(&self, reindeer: &Reindeer) -> impl Iterator<Item = (Reindeer, u64)> {
+        let turns = [
+            reindeer.facing.clockwise(),
+            reindeer.facing.counter_clockwise(),
+        ]
+        .map(|facing| {
+            let turned = Reindeer {
+                facing,
+                pos: reindeer.pos,
+            };
+            (turned, TURN_COST)
+        });
+
+        let step = self
+            .step_reindeer(*reindeer)
+            .map(|stepped| (stepped, STEP_COST));
+
+        turns.into_iter().chain(step)
+    }
+
+    fn min_score(&self) -> Option<u64> {
+        dijkstra(
+            self.start,
+            |reindeer| self.moves(reindeer),
+            |reindeer| reindeer.pos == self.end,
+        )
+        .goal_cost()
+    }
+
+    fn num_tiles_on_best_paths(&self) -> usize {
+        let result = Search::new().all_predecessors().dijkstra(
+            self.start,
+            |reindeer| self.moves(reindeer),
+            |reindeer| reindeer.pos == self.end,
+        );
+
+        result
+            .dag()
+            .nodes()
+            .into_iter()
+            .map(|reindeer| reindeer.pos)
+            .collect::<HashSet<_>>()
+            .len()
+    }
+}
@@ -1,7 +1,6 @@
-use stdcur_pos
+use std::{
     cmp::Reverse,
     collections::{BinaryHeap, HashSet},
-    env::args,
     iter::Map,
     rc::Rc,
 };
@@ -9,21 +8,18 @@ use stdcur_pos
 use advent_rust_lib::{
     direction::Direction,
     grid::{Pos2D, RectangleGrid},
-    read::filtered_input,
+    read::Input,
 };
-use rayon::iter::Rev;
 
 fn main() {
-    let mut arg_numbers = args()
-        .skip(1)
-        .flat_map(|line| str::parse::<usize>(&line).ok());
-    let dim = arg_numbers.next().unwrap();
-    let count = arg_numbers.next().unwrap();
+    let input = Input::from_env().unwrap();
+    let dim = input.params().get("dim").unwrap();
+    let count = input.params().get("count").unwrap();
 
-    let mem_space = MemSpace::from_input(dim, count, filtered_input(&[3])).unwrap();
+    let mem_space = MemSpace::from_input(dim, count, input.lines().unwrap()).unwrap();
     part_1(&mem_space);
 
-    let full_mem_space = FillingMemSpace::from_input(dim, filtered_input(&[3])).unwrap();
+    let full_mem_space = FillingMemSpace::from_input(dim, input.lines().unwrap()).unwrap();
     part_2(full_mem_space);
 }
 
@@ -143,7 +139,7 @@ struct RevLinkedNodeInternal {
     next: RevLinkedNode,
 }
 
-pub type RevLinkedNode = Option<Rc<RevLinkedNodeInternal>>;
+type RevLinkedNode = Option<Rc<RevLinkedNodeInternal>>;
 
 impl RevLinkedNodeInternal {
     pub fn new() -> RevLinkedNode {
@@ -275,7 +271,7 @@ impl<I: IntoIterator<Item = S>, S> FillingMemSpace<I, S> {
     }
 
     /// Returns the append to the path, backwards, if a valid one exists.
-    fn complete_pathcur_pos
+    fn complete_path(
         &self,
         truncated_path: &[Pos2D],
         failed_prior: &[Pos2D],
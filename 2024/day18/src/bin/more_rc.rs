@@ -2,28 +2,24 @@ use std::{
     borrow::Cow,
     cmp::Reverse,
     collections::{BinaryHeap, HashSet},
-    env::args,
     iter::Map,
-    rc::Rc,
 };
 
 use advent_rust_lib::{
     direction::Direction,
     grid::{Pos2D, RectangleGrid},
-    read::filtered_input,
+    read::Input,
 };
 
 fn main() {
-    let mut arg_numbers = args()
-        .skip(1)
-        .flat_map(|line| str::parse::<usize>(&line).ok());
-    let dim = arg_numbers.next().unwrap();
-    let count = arg_numbers.next().unwrap();
+    let input = Input::from_env().unwrap();
+    let dim = input.params().get("dim").unwrap();
+    let count = input.params().get("count").unwrap();
 
-    let mem_space = MemSpace::from_input(dim, count, filtered_input(&[3])).unwrap();
+    let mem_space = MemSpace::from_input(dim, count, input.lines().unwrap()).unwrap();
     part_1(&mem_space);
 
-    let full_mem_space = FillingMemSpace::from_input(dim, filtered_input(&[3])).unwrap();
+    let full_mem_space = FillingMemSpace::from_input(dim, input.lines().unwrap()).unwrap();
     part_2(full_mem_space);
 }
 
@@ -141,7 +137,7 @@ pub struct FillingMemSpace<I: IntoIterator<Item = S>, S> {
 struct MemorySteppedPos<'a> {
     pub pos: Pos2D,
     pub steps: u64,
-    pub memory: Cow<'a, Vec<Pos2D>>,
+    pub memory: Cow<'a, [Pos2D]>,
 }
 
 impl PartialOrd for MemorySteppedPos<'_> {
@@ -248,7 +244,7 @@ impl<I: IntoIterator<Item = S>, S> FillingMemSpace<I, S> {
 
             let mut new_memory = top.memory.into_owned();
             new_memory.push(top.pos);
-            let new_memory: Cow<Vec<_>> = Cow::Owned(new_memory);
+            let new_memory: Cow<[_]> = Cow::Owned(new_memory);
 
             let new_visit_locs = Direction::all()
                 .into_iter()
@@ -271,6 +267,6 @@ impl<I: IntoIterator<Item = S>, S> FillingMemSpace<I, S> {
             to_visit.extend(new_visit_locs);
         }
 
-        to_visit.peek().map(|x| x.0.memory.as_ref().clone())
+        to_visit.peek().map(|x| x.0.memory.to_vec())
     }
 }
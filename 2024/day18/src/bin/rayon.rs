@@ -1,9 +1,6 @@
 use std::{
     cmp::Reverse,
     collections::{BinaryHeap, HashSet},
-    env::args,
-    fs::File,
-    io::{BufRead, BufReader},
     iter::Map,
     rc::Rc,
 };
@@ -11,24 +8,19 @@ use std::{
 use advent_rust_lib::{
     direction::Direction,
     grid::{Pos2D, RectangleGrid},
-    read::filtered_input,
+    read::Input,
 };
 use rayon::iter::{ParallelBridge, ParallelIterator};
 
 fn main() {
-    let mut arg_numbers = args()
-        .skip(1)
-        .flat_map(|line| str::parse::<usize>(&line).ok());
-    let dim = arg_numbers.next().unwrap();
-    let count = arg_numbers.next().unwrap();
+    let input = Input::from_env().unwrap();
+    let dim = input.params().get("dim").unwrap();
+    let count = input.params().get("count").unwrap();
 
-    let mem_space = MemSpace::from_input(dim, count, filtered_input(&[3])).unwrap();
+    let mem_space = MemSpace::from_input(dim, count, input.lines().unwrap()).unwrap();
     part_1(&mem_space);
 
-    let input_file = BufReader::new(File::open(args().nth(3).unwrap()).unwrap())
-        .lines()
-        .map(|line| line.unwrap());
-    let full_mem_space = FillingMemSpace::from_input(dim, input_file).unwrap();
+    let full_mem_space = FillingMemSpace::from_input(dim, input.lines().unwrap()).unwrap();
     part_2(full_mem_space);
 }
 
@@ -0,0 +1,192 @@
+use std::{collections::HashSet, iter::Map};
+
+use advent_rust_lib::{
+    direction::Direction,
+    grid::{Pos2D, RectangleGrid},
+    search::bfs,
+    solution::{Params, Solution, SolutionResult},
+};
+
+/// Takes the grid size as `--dim` and the part 1 byte count as `--count`.
+pub struct Day18;
+
+impl Solution for Day18 {
+    fn year(&self) -> u16 {
+        2024
+    }
+
+    fn day(&self) -> u8 {
+        18
+    }
+
+    fn part_1(&self, input: &str, params: &Params) -> SolutionResult {
+        let mem_space =
+            MemSpace::from_input(params.get("dim")?, params.get("count")?, input.lines())
+                .ok_or("Invalid byte position")?;
+        let steps = mem_space.shortest_path().ok_or("The exit is unreachable")?;
+        Ok(steps.to_string())
+    }
+
+    fn part_2(&self, input: &str, params: &Params) -> SolutionResult {
+        let mut mem_space = FillingMemSpace::from_input(params.get("dim")?, input.lines())
+            .ok_or("Invalid byte position")?;
+        let first_invalid = mem_space
+            .first_invalid_fill()
+            .ok_or("The exit is never blocked")?;
+        Ok(format!("{},{}", first_invalid.x, first_invalid.y))
+    }
+}
+
+#[derive(Debug)]
+pub struct MemSpace {
+    // True is an obstacle
+    grid: RectangleGrid<bool>,
+}
+
+impl MemSpace {
+    pub fn from_input<S, I>(dim: usize, count: usize, input: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut grid = RectangleGrid::default_with_dim(dim, dim, false);
+
+        let input = input.into_iter().map(|line| {
+            let line = line.as_ref();
+            let (x, y) = line.split_at(line.find(',')?);
+            let x = str::parse(x).ok()?;
+            let y = str::parse(&y[1..]).ok()?;
+            Some(Pos2D::new(x, y))
+        });
+
+        for block_pos in input.take(count) {
+            let block_pos = block_pos?;
+            *grid.get_mut(block_pos)? = true;
+        }
+
+        Some(Self { grid })
+    }
+}
+
+/// Unblocked positions next to `pos`.
+fn open_neighbors(grid: &RectangleGrid<bool>, pos: Pos2D) -> impl Iterator<Item = Pos2D> + '_ {
+    Direction::all()
+        .into_iter()
+        .flat_map(move |dir| pos.step_dir(dir))
+        .filter(|new_pos| grid.get(*new_pos) == Some(&false))
+}
+
+impl MemSpace {
+    pub fn shortest_path(&self) -> Option<usize> {
+        let final_pos = Pos2D::new(self.grid.x_max() - 1, self.grid.y_max() - 1);
+
+        bfs(
+            Pos2D::new(0, 0),
+            |pos| open_neighbors(&self.grid, *pos),
+            |pos| *pos == final_pos,
+        )
+        .goal_cost()
+    }
+}
+
+pub struct FillingMemSpace<I: IntoIterator<Item = S>, S> {
+    // True is an obstacle
+    grid: RectangleGrid<bool>,
+    #[expect(clippy::type_complexity, reason = "broken apart iterator")]
+    fill: Map<<I as IntoIterator>::IntoIter, fn(S) -> Option<Pos2D>>,
+    path: Vec<Pos2D>,
+}
+
+impl<I: IntoIterator<Item = S>, S> FillingMemSpace<I, S> {
+    fn line_transform(line: S) -> Option<Pos2D>
+    where
+        S: AsRef<str>,
+    {
+        let line = line.as_ref();
+        let (x, y) = line.split_at(line.find(',')?);
+        let x = str::parse(x).ok()?;
+        let y = str::parse(&y[1..]).ok()?;
+        Some(Pos2D::new(x, y))
+    }
+
+    pub fn from_input(dim: usize, input: I) -> Option<Self>
+    where
+        S: AsRef<str>,
+    {
+        let grid = RectangleGrid::default_with_dim(dim, dim, false);
+        let fill = input.into_iter().map(Self::line_transform as fn(_) -> _);
+
+        let y_steps = (0..dim - 1).map(|y| Pos2D::new(0, y));
+        let x_steps = (1..dim - 1).map(|x| Pos2D::new(x, 0));
+        let path = y_steps.chain(x_steps).collect();
+
+        Some(Self { grid, fill, path })
+    }
+
+    pub fn first_invalid_fill(&mut self) -> Option<Pos2D> {
+        loop {
+            let next_fill = self.fill.next()??;
+            *self.grid.get_mut(next_fill)? = true;
+            if !self.validate(next_fill)? {
+                return Some(next_fill);
+            }
+        }
+    }
+
+    fn validate(&mut self, next_fill: Pos2D) -> Option<bool> {
+        if let Some(overlap_idx) = self.path.iter().position(|entry| *entry == next_fill) {
+            // Retain the path up until the overlap and attempt to form a new completition
+            self.path.truncate(overlap_idx);
+
+            let mut new_paths = (1..self.path.len())
+                .rev()
+                .flat_map(|test_idx| {
+                    self.complete_path(&self.path[..test_idx], &self.path[test_idx..])
+                })
+                .chain(std::iter::once(self.complete_path(&[], &[])).flatten());
+
+            if let Some(new_path) = new_paths.next() {
+                self.path = new_path;
+                Some(true)
+            } else {
+                Some(false)
+            }
+        } else {
+            Some(true)
+        }
+    }
+
+    /// Returns a new path from the valid truncation
+    pub fn complete_path(
+        &self,
+        truncated_path: &[Pos2D],
+        failed_prior: &[Pos2D],
+    ) -> Option<Vec<Pos2D>> {
+        let final_pos = Pos2D::new(self.grid.x_max() - 1, self.grid.y_max() - 1);
+
+        let start = Pos2D::new(0, 0);
+
+        let mut excluded: HashSet<Pos2D> = HashSet::new();
+        excluded.extend(truncated_path.iter());
+        excluded.extend(failed_prior);
+        if excluded.contains(&start) {
+            return None;
+        }
+
+        let found = bfs(
+            start,
+            |pos| {
+                open_neighbors(&self.grid, *pos)
+                    .filter(|new_pos| !excluded.contains(new_pos))
+                    .collect::<Vec<_>>()
+            },
+            |pos| *pos == final_pos,
+        )
+        .path()?;
+
+        // The path up to, but not including, the exit
+        let mut memory = truncated_path.to_vec();
+        memory.extend_from_slice(&found[..found.len() - 1]);
+        Some(memory)
+    }
+}
@@ -0,0 +1,92 @@
+use std::collections::HashSet;
+
+use advent_rust_lib::{
+    memo::Memo,
+    solution::{Params, Solution, SolutionResult},
+};
+
+/// Returns (patterns, designs).
+fn parse(input: &str) -> (HashSet<String>, Vec<String>) {
+    let mut input = input.lines();
+    let patterns: HashSet<String> = input
+        .by_ref()
+        .next()
+        .unwrap()
+        .split(',')
+        .map(|x| x.trim().to_string())
+        .collect();
+
+    // Skip empty line
+    input.by_ref().next();
+
+    let designs: Vec<String> = input.map(str::to_string).collect();
+
+    (patterns, designs)
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    fn year(&self) -> u16 {
+        2024
+    }
+
+    fn day(&self) -> u8 {
+        19
+    }
+
+    fn part_1(&self, input: &str, _params: &Params) -> SolutionResult {
+        let (patterns, designs) = parse(input);
+        Ok(part_1(&patterns, &designs).to_string())
+    }
+
+    fn part_2(&self, input: &str, _params: &Params) -> SolutionResult {
+        let (patterns, designs) = parse(input);
+        Ok(part_2(&patterns, &designs).to_string())
+    }
+}
+
+fn part_1(patterns: &HashSet<String>, designs: &[String]) -> usize {
+    designs
+        .iter()
+        .filter(|design| find_pattern(patterns, design))
+        .count()
+}
+
+fn part_2(patterns: &HashSet<String>, designs: &[String]) -> u64 {
+    designs
+        .iter()
+        .map(|design| count_satisfying(patterns, design))
+        .sum()
+}
+
+fn find_pattern(patterns: &HashSet<String>, design: &str) -> bool {
+    if patterns.contains(design) {
+        true
+    } else {
+        // Skip full slice
+        for end_idx in (0..design.len()).rev() {
+            let sub_design = &design[0..end_idx];
+            let remaining_design = &design[end_idx..];
+            if patterns.contains(sub_design) && find_pattern(patterns, remaining_design) {
+                return true;
+            }
+        }
+
+        // None of the slices resolved into a match
+        false
+    }
+}
+
+fn count_satisfying(patterns: &HashSet<String>, design: &str) -> u64 {
+    // Ways to build the design from each start index onwards
+    Memo::dense(design.len() + 1).solve(0, &|recurse, start: &usize| {
+        if *start == design.len() {
+            return 1;
+        }
+        (start + 1..=design.len())
+            .filter(|end| patterns.contains(&design[*start..*end]))
+            .map(recurse)
+            .sum()
+    })
+}
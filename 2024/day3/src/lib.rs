@@ -0,0 +1,119 @@
+use std::{num::ParseIntError, sync::LazyLock};
+
+use advent_rust_lib::solution::{Params, Solution, SolutionResult};
+use regex::{Captures, Regex};
+use thiserror::Error;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    fn year(&self) -> u16 {
+        2024
+    }
+
+    fn day(&self) -> u8 {
+        3
+    }
+
+    fn part_1(&self, input: &str, _params: &Params) -> SolutionResult {
+        Ok(part_1(input)?.to_string())
+    }
+
+    fn part_2(&self, input: &str, _params: &Params) -> SolutionResult {
+        Ok(part_2(input)?.to_string())
+    }
+}
+
+fn part_1(input: &str) -> Result<u64, ParseIntError> {
+    get_muls(input).map(|x| x.map(|(lhs, rhs)| lhs * rhs)).sum()
+}
+
+fn part_2(input: &str) -> Result<u64, StatementErr> {
+    let mut sum = 0;
+    let mut active = true;
+    for statement in get_statements(input) {
+        let statement = statement?;
+        if active {
+            match statement {
+                Statement::Dont => {
+                    active = false;
+                }
+                Statement::Mul(lhs, rhs) => {
+                    sum += lhs * rhs;
+                }
+                Statement::Do => (),
+            }
+        } else if statement == Statement::Do {
+            active = true;
+        }
+    }
+    Ok(sum)
+}
+
+fn get_muls(to_parse: &str) -> impl Iterator<Item = Result<(u64, u64), ParseIntError>> + use<'_> {
+    static MUL_STATEMENT: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r#"mul\((\d+),(\d+)\)"#).unwrap());
+
+    (*MUL_STATEMENT).captures_iter(to_parse).map(|x| {
+        let extracted: [_; 2] = x.extract().1;
+        Ok((str::parse(extracted[0])?, str::parse(extracted[1])?))
+    })
+}
+
+fn get_statements(
+    to_parse: &str,
+) -> impl Iterator<Item = Result<Statement, StatementErr>> + use<'_> {
+    static MUL_STATEMENT: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r#"(don't\(\))|(do\(\))|(mul\((\d+),(\d+)\))"#).unwrap());
+
+    (*MUL_STATEMENT)
+        .captures_iter(to_parse)
+        .map(Statement::from_capture)
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Statement {
+    Do,
+    Dont,
+    Mul(u64, u64),
+}
+
+#[derive(Debug, Error)]
+pub enum StatementErr {
+    #[error("An invalid regex result was returned")]
+    InvalidRegex,
+    #[error("{0}")]
+    ParseIntError(ParseIntError),
+}
+
+impl From<ParseIntError> for StatementErr {
+    fn from(value: ParseIntError) -> Self {
+        Self::ParseIntError(value)
+    }
+}
+
+impl Statement {
+    pub fn from_capture(capture: Captures<'_>) -> Result<Self, StatementErr> {
+        let mut subcaptures = capture.iter();
+        match subcaptures
+            .by_ref()
+            .next()
+            .flatten()
+            .ok_or(StatementErr::InvalidRegex)?
+            .as_str()
+        {
+            "don't()" => Ok(Self::Dont),
+            "do()" => Ok(Self::Do),
+            x if x.starts_with("mul(") => {
+                let mut digits = subcaptures
+                    .flatten()
+                    .skip(1)
+                    .map(|y| str::parse(y.as_str()));
+                let lhs = digits.by_ref().next().ok_or(StatementErr::InvalidRegex)??;
+                let rhs = digits.next().ok_or(StatementErr::InvalidRegex)??;
+                Ok(Self::Mul(lhs, rhs))
+            }
+            _ => Err(StatementErr::InvalidRegex),
+        }
+    }
+}
@@ -0,0 +1,196 @@
+use advent_rust_lib::{
+    grid::RectangleGrid,
+    solution::{Params, Solution, SolutionResult},
+};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    fn year(&self) -> u16 {
+        2024
+    }
+
+    fn day(&self) -> u8 {
+        4
+    }
+
+    fn part_1(&self, input: &str, _params: &Params) -> SolutionResult {
+        let grid = RectangleGrid::try_from_iter_strict(input.lines().map(str::chars))?;
+        Ok(part_1(&grid).to_string())
+    }
+
+    fn part_2(&self, input: &str, _params: &Params) -> SolutionResult {
+        let lines: Vec<_> = input.lines().collect();
+        Ok(part_2(&lines).to_string())
+    }
+}
+
+fn part_1(grid: &RectangleGrid<char>) -> usize {
+    let rows = grid.lines().map(|line| line.iter().collect::<String>());
+    let columns = grid
+        .columns()
+        .chain(grid.diagonals())
+        .chain(grid.anti_diagonals())
+        .map(|line| line.collect::<String>());
+
+    // Forwards and backwards in every direction
+    rows.chain(columns)
+        .map(|line| line.matches("XMAS").count() + line.matches("SAMX").count())
+        .sum()
+}
+
+fn part_2<S: AsRef<str>>(lines: &[S]) -> usize {
+    (1..(lines.len().saturating_sub(1)))
+        .map(|y| {
+            (1..(lines[0].as_ref().len() - 1))
+                .map(|x| search_mas_cross(lines, (x, y).into()))
+                .filter(|x| *x)
+                .count()
+        })
+        .sum()
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Pos2D {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Pos2D {
+    pub fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+
+    // -- START Directional calculations -- //
+
+    pub fn down(&self) -> Option<Self> {
+        Some(Self::new(self.x, self.y.checked_add(1)?))
+    }
+
+    pub fn down_right(&self) -> Option<Self> {
+        Some(Self::new(self.x.checked_add(1)?, self.y.checked_add(1)?))
+    }
+
+    pub fn right(&self) -> Option<Self> {
+        Some(Self::new(self.x.checked_add(1)?, self.y))
+    }
+
+    pub fn up_right(&self) -> Option<Self> {
+        Some(Self::new(self.x.checked_add(1)?, self.y.checked_sub(1)?))
+    }
+
+    pub fn up(&self) -> Option<Self> {
+        Some(Self::new(self.x, self.y.checked_sub(1)?))
+    }
+
+    pub fn up_left(&self) -> Option<Self> {
+        Some(Self::new(self.x.checked_sub(1)?, self.y.checked_sub(1)?))
+    }
+
+    pub fn left(&self) -> Option<Self> {
+        Some(Self::new(self.x.checked_sub(1)?, self.y))
+    }
+
+    pub fn down_left(&self) -> Option<Self> {
+        Some(Self::new(self.x.checked_sub(1)?, self.y.checked_add(1)?))
+    }
+
+    // -- END Directional calculations -- //
+
+    pub fn surrounding_pos(&self) -> impl Iterator<Item = Self> {
+        [
+            Some(Self::new(self.x + 1, self.y)),
+            Some(Self::new(self.x + 1, self.y + 1)),
+            Some(Self::new(self.x, self.y + 1)),
+            // -- negative x -- //
+            self.x.checked_sub(1).map(|x| Self::new(x, self.y)),
+            self.x.checked_sub(1).map(|x| Self::new(x, self.y + 1)),
+            // -- negative y -- //
+            self.y.checked_sub(1).map(|y| Self::new(self.x, y)),
+            self.y.checked_sub(1).map(|y| Self::new(self.x + 1, y)),
+            // -- negative x and y -- //
+            self.x
+                .checked_sub(1)
+                .and_then(|x| self.y.checked_sub(1).map(|y| Self::new(x, y))),
+        ]
+        .into_iter()
+        .flatten()
+    }
+
+    pub fn surrounding_lines(&self) -> impl Iterator<Item = SurroundingLineIter> + use<'_> {
+        [
+            Self::down,
+            Self::down_right,
+            Self::right,
+            Self::up_right,
+            Self::up,
+            Self::up_left,
+            Self::left,
+            Self::down_left,
+        ]
+        .into_iter()
+        .map(|func| SurroundingLineIter::new(*self, func))
+    }
+
+    pub fn get_arr_char<S, A>(&self, arr: A) -> Option<char>
+    where
+        S: AsRef<str>,
+        A: AsRef<[S]>,
+    {
+        arr.as_ref().get(self.y)?.as_ref().chars().nth(self.x)
+    }
+}
+
+impl From<(usize, usize)> for Pos2D {
+    fn from(value: (usize, usize)) -> Self {
+        Self {
+            x: value.0,
+            y: value.1,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct SurroundingLineIter {
+    cur_pos: Pos2D,
+    change: fn(&Pos2D) -> Option<Pos2D>,
+}
+
+impl SurroundingLineIter {
+    pub fn new(pos: Pos2D, change: fn(&Pos2D) -> Option<Pos2D>) -> Self {
+        Self {
+            cur_pos: pos,
+            change,
+        }
+    }
+}
+
+impl Iterator for SurroundingLineIter {
+    type Item = Pos2D;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.cur_pos = (self.change)(&self.cur_pos)?;
+        Some(self.cur_pos)
+    }
+}
+
+fn search_mas_cross<S>(array: &[S], pos: Pos2D) -> bool
+where
+    S: AsRef<str>,
+{
+    let mid = pos.get_arr_char(array) == Some('A');
+
+    let check_corners = |up: Option<Pos2D>, down: Option<Pos2D>| {
+        up.and_then(|up_left| Some((up_left, down?)))
+            .map(|(up_left, down_right)| match up_left.get_arr_char(array) {
+                Some('M') => down_right.get_arr_char(array) == Some('S'),
+                Some('S') => down_right.get_arr_char(array) == Some('M'),
+                // Upper left needs to be M or S
+                _ => false,
+            })
+            == Some(true)
+    };
+
+    mid && check_corners(pos.up_left(), pos.down_right())
+        && check_corners(pos.up_right(), pos.down_left())
+}
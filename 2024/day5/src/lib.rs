@@ -0,0 +1,120 @@
+use std::collections::HashMap;
+
+use advent_rust_lib::{
+    parse::{sections, ParseError},
+    solution::{Params, Solution, SolutionResult},
+};
+use itertools::Itertools;
+
+/// Each page mapped to the pages that must come before it.
+type Rules = HashMap<u64, Vec<u64>>;
+
+/// Returns (page rule mapping, page lines).
+fn parse(input: &str) -> Result<(Rules, Vec<Vec<u64>>), ParseError> {
+    let mut sections = sections(input);
+
+    let rules = sections
+        .next()
+        .into_iter()
+        .flat_map(|section| section.lines())
+        .map(|line| line.int_array::<u64, 2>())
+        .collect::<Result<Vec<_>, _>>()?;
+    let mapping: Rules = rules
+        .into_iter()
+        .sorted_by(|[_, y0], [_, y1]| y0.cmp(y1))
+        .chunk_by(|[_, y]| *y)
+        .into_iter()
+        .map(|(group, vals)| (group, vals.into_iter().map(|[x, _]| x).collect()))
+        .collect();
+
+    let pages = sections
+        .flat_map(|section| section.lines())
+        .map(|line| line.ints())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((mapping, pages))
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    fn year(&self) -> u16 {
+        2024
+    }
+
+    fn day(&self) -> u8 {
+        5
+    }
+
+    fn part_1(&self, input: &str, _params: &Params) -> SolutionResult {
+        let (mapping, pages) = parse(input)?;
+        Ok(part_1(&mapping, &pages).to_string())
+    }
+
+    fn part_2(&self, input: &str, _params: &Params) -> SolutionResult {
+        let (mapping, pages) = parse(input)?;
+        Ok(part_2(&mapping, &pages).to_string())
+    }
+}
+
+fn part_1(mapping: &HashMap<u64, Vec<u64>>, pages: &[Vec<u64>]) -> u64 {
+    let valid_pages = pages.iter().filter(|line| {
+        let mut previous = Vec::new();
+        for item in line.iter() {
+            if let Some(prev_reqs) = mapping.get(item) {
+                if prev_reqs
+                    .iter()
+                    .any(|req| line.contains(req) && !previous.contains(req))
+                {
+                    return false;
+                }
+            }
+
+            previous.push(*item);
+        }
+        true
+    });
+
+    valid_pages.map(|line| line[line.len() / 2]).sum()
+}
+
+fn part_2(mapping: &HashMap<u64, Vec<u64>>, pages: &[Vec<u64>]) -> u64 {
+    let invalid_pages = pages.iter().filter(|line| {
+        let mut previous = Vec::new();
+        for item in line.iter() {
+            if let Some(prev_reqs) = mapping.get(item) {
+                if prev_reqs
+                    .iter()
+                    .any(|req| line.contains(req) && !previous.contains(req))
+                {
+                    return true;
+                }
+            }
+
+            previous.push(*item);
+        }
+        false
+    });
+
+    let reordered_pages = invalid_pages.map(|line| reorder(mapping, line.clone()));
+
+    reordered_pages.map(|line| line[line.len() / 2]).sum()
+}
+
+fn reorder(mapping: &HashMap<u64, Vec<u64>>, mut line: Vec<u64>) -> Vec<u64> {
+    let mut previous = Vec::new();
+    for (idx, item) in line.iter().enumerate() {
+        if let Some(prev_reqs) = mapping.get(item) {
+            for req in prev_reqs {
+                if line.contains(req) && !previous.contains(req) {
+                    line.remove(line.iter().position(|x| x == req).unwrap());
+                    line.insert(idx, *req);
+                    return reorder(mapping, line);
+                }
+            }
+        }
+
+        previous.push(*item);
+    }
+    line
+}
@@ -0,0 +1,448 @@
+use std::collections::HashMap;
+
+use advent_rust_lib::{
+    grid::{Pos2D, RectangleGrid},
+    solution::{Params, Solution, SolutionError, SolutionResult},
+};
+
+fn parse(input: &str) -> Result<GuardMap, SolutionError> {
+    GuardMap::from_input(input)
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    fn year(&self) -> u16 {
+        2024
+    }
+
+    fn day(&self) -> u8 {
+        6
+    }
+
+    fn part_1(&self, input: &str, _params: &Params) -> SolutionResult {
+        Ok(parse(input)?.count_unique_until_exit().to_string())
+    }
+
+    fn part_2(&self, input: &str, _params: &Params) -> SolutionResult {
+        Ok(parse(input)?.num_possible_blockages().to_string())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum GuardDir {
+    Up,
+    Left,
+    Right,
+    Down,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Guard {
+    pos: Pos2D,
+    dir: GuardDir,
+}
+
+impl Guard {
+    pub fn new(pos: Pos2D) -> Self {
+        Self {
+            pos,
+            dir: GuardDir::Up,
+        }
+    }
+
+    pub fn next(&self) -> Option<Self> {
+        let pos = match self.dir {
+            GuardDir::Up => self.pos.up(),
+            GuardDir::Left => self.pos.left(),
+            GuardDir::Right => self.pos.right(),
+            GuardDir::Down => self.pos.down(),
+        }?;
+
+        Some(Self { pos, dir: self.dir })
+    }
+
+    pub fn rotate(&mut self) {
+        self.dir = match self.dir {
+            GuardDir::Up => GuardDir::Right,
+            GuardDir::Right => GuardDir::Down,
+            GuardDir::Down => GuardDir::Left,
+            GuardDir::Left => GuardDir::Up,
+        }
+    }
+
+    pub fn pos(&self) -> Pos2D {
+        self.pos
+    }
+
+    pub fn dir(&self) -> GuardDir {
+        self.dir
+    }
+}
+
+#[derive(Debug)]
+pub struct GuardMap {
+    // (y, [x, ...]). x vector is sorted.
+    obstacles: HashMap<usize, Vec<usize>>,
+    guard: Guard,
+    max_x: usize,
+    max_y: usize,
+}
+
+#[cfg(not(feature = "print"))]
+type SpecLoopReturn = bool;
+#[cfg(feature = "print")]
+type SpecLoopReturn = Option<Vec<Pos2D>>;
+
+impl GuardMap {
+    pub fn from_input(input: &str) -> Result<Self, SolutionError> {
+        let (grid, markers) = RectangleGrid::parse_chars(input, &['^'], |c| c == '#')?;
+        let guard = Guard::new(markers.first('^').ok_or("No guard (^) in the map")?);
+
+        let obstacles = grid
+            .lines()
+            .enumerate()
+            .filter_map(|(y, line)| {
+                let x_vals: Vec<_> = line
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, blocked)| **blocked)
+                    .map(|(x, _)| x)
+                    .collect();
+                (!x_vals.is_empty()).then_some((y, x_vals))
+            })
+            .collect();
+
+        Ok(Self {
+            obstacles,
+            guard,
+            max_x: grid.x_max(),
+            max_y: grid.y_max(),
+        })
+    }
+
+    pub fn count_unique_until_exit(&self) -> usize {
+        let mut guard = self.guard;
+        let mut unique = vec![guard.pos()];
+
+        #[cfg(feature = "print")]
+        let mut print_unique = vec![guard.pos()];
+
+        // While still in bounds
+        while guard.pos().x < self.max_x && guard.pos().y < self.max_y {
+            if let Some(next_guard) = guard.next() {
+                // Checking for dupes here speeds along running repeated paths
+                if let Err(insert_pos) = unique.binary_search(&next_guard.pos()) {
+                    // Check if next position is an invalid tile
+                    if self
+                        .obstacles
+                        .get(&next_guard.pos().y)
+                        .map(|blocked_x| blocked_x.binary_search(&next_guard.pos().x).is_ok())
+                        == Some(true)
+                    {
+                        // Rotate 90 and rerun
+                        guard.rotate();
+                        continue;
+                    } else {
+                        // New tile is unique, insert and set as the new val
+                        unique.insert(insert_pos, next_guard.pos());
+                        guard = next_guard;
+
+                        #[cfg(feature = "print")]
+                        print_unique.push(next_guard.pos());
+                    }
+                } else {
+                    // New tile is not unique, set as the new val
+                    guard = next_guard;
+
+                    #[cfg(feature = "print")]
+                    print_unique.push(next_guard.pos());
+                }
+            } else {
+                break;
+            }
+        }
+
+        #[cfg(feature = "print")]
+        {
+            if guard.pos().x >= self.max_x || guard.pos().y >= self.max_y {
+                guard.rotate();
+                guard.rotate();
+                guard = guard.next().unwrap();
+                guard.rotate();
+                guard.rotate();
+            }
+            let mut print_vec_deduped = Vec::with_capacity(print_unique.len());
+            for pos in print_unique.into_iter().rev() {
+                if !print_vec_deduped.contains(&pos) {
+                    print_vec_deduped.push(pos);
+                }
+            }
+
+            self.print_map(
+                &guard,
+                print_vec_deduped.into_iter().rev(),
+                None,
+                std::iter::empty(),
+            );
+        }
+
+        unique.len().saturating_sub(1)
+    }
+
+    fn speculate_loop(
+        &self,
+        guard: &Guard,
+        next_guard: &Guard,
+        // MUST be sorted
+        unique: &[Guard],
+    ) -> SpecLoopReturn {
+        let block_pos = next_guard.pos();
+
+        // Speculate on current position being rotated by 90
+        let mut speculative_guard = *guard;
+        speculative_guard.rotate();
+
+        if let Ok(start_idx) = unique.binary_search_by_key(&speculative_guard.pos(), |x| x.pos()) {
+            for idx in (0..start_idx).rev() {
+                if unique[idx].pos() == speculative_guard.pos() {
+                    if unique[idx] == speculative_guard {
+                        #[cfg(not(feature = "print"))]
+                        return true;
+                        #[cfg(feature = "print")]
+                        return Some(Vec::new());
+                    }
+                } else {
+                    break;
+                }
+            }
+
+            for entry in &unique[start_idx..] {
+                if entry.pos() == speculative_guard.pos() {
+                    if *entry == speculative_guard {
+                        #[cfg(not(feature = "print"))]
+                        return true;
+                        #[cfg(feature = "print")]
+                        return Some(Vec::new());
+                    }
+                } else {
+                    break;
+                }
+            }
+        }
+
+        let mut spec_unique = vec![speculative_guard];
+
+        #[cfg(feature = "print")]
+        let mut spec_unique_print = vec![speculative_guard.pos()];
+
+        while let Some(next_spec) = speculative_guard.next() {
+            if next_spec.pos().x >= self.max_x || next_spec.pos().y >= self.max_y {
+                #[cfg(not(feature = "print"))]
+                return false;
+                #[cfg(feature = "print")]
+                return None;
+            }
+
+            if let Err(insert_pos) = spec_unique.binary_search(&next_spec) {
+                // Check if next position is an invalid tile
+                if (self
+                    .obstacles
+                    .get(&next_spec.pos().y)
+                    .map(|blocked_x| blocked_x.binary_search(&next_spec.pos().x).is_ok())
+                    == Some(true))
+                    || next_spec.pos() == block_pos
+                {
+                    // Rotate 90 and rerun
+                    speculative_guard.rotate();
+                } else {
+                    // Duplicate with primary looping
+                    if let Ok(start_idx) =
+                        unique.binary_search_by_key(&next_spec.pos(), |x| x.pos())
+                    {
+                        for idx in (0..start_idx).rev() {
+                            if unique[idx].pos() == next_spec.pos() {
+                                if unique[idx] == next_spec {
+                                    #[cfg(not(feature = "print"))]
+                                    return true;
+                                    #[cfg(feature = "print")]
+                                    return Some(spec_unique_print);
+                                }
+                            } else {
+                                break;
+                            }
+                        }
+
+                        for entry in &unique[start_idx..] {
+                            if entry.pos() == next_spec.pos() {
+                                if *entry == next_spec {
+                                    #[cfg(not(feature = "print"))]
+                                    return true;
+                                    #[cfg(feature = "print")]
+                                    return Some(spec_unique_print);
+                                }
+                            } else {
+                                break;
+                            }
+                        }
+                    }
+
+                    // New tile is unique, insert and set as the new val
+                    spec_unique.insert(insert_pos, next_spec);
+                    speculative_guard = next_spec;
+
+                    #[cfg(feature = "print")]
+                    spec_unique_print.push(next_spec.pos());
+                }
+            } else {
+                // Repeat in the speculative loop
+                #[cfg(not(feature = "print"))]
+                return true;
+                #[cfg(feature = "print")]
+                return Some(spec_unique_print);
+            }
+        }
+
+        #[cfg(not(feature = "print"))]
+        return false;
+        #[cfg(feature = "print")]
+        return None;
+    }
+
+    pub fn num_possible_blockages(&self) -> usize {
+        let mut guard = self.guard;
+        let mut unique = vec![guard];
+        let mut num_blocks = 0;
+
+        #[cfg(feature = "print")]
+        let mut print_unique = vec![guard.pos()];
+
+        // While still in bounds
+        while guard.pos().x < self.max_x && guard.pos().y < self.max_y {
+            if let Some(next_guard) = guard.next() {
+                // Checking for dupes here speeds along running repeated paths
+                if let Err(insert_pos) = unique.binary_search_by_key(&next_guard.pos(), |x| x.pos())
+                {
+                    // Check if next position is an invalid tile
+                    if self
+                        .obstacles
+                        .get(&next_guard.pos().y)
+                        .map(|blocked_x| blocked_x.binary_search(&next_guard.pos().x).is_ok())
+                        == Some(true)
+                    {
+                        // Rotate 90 and rerun
+                        guard.rotate();
+                    } else {
+                        let loops = self.speculate_loop(&guard, &next_guard, &unique);
+
+                        #[cfg(not(feature = "print"))]
+                        if loops {
+                            num_blocks += 1;
+                        }
+
+                        #[cfg(feature = "print")]
+                        if let Some(spec_unique_print) = loops {
+                            num_blocks += 1;
+
+                            let mut print_vec_deduped = Vec::with_capacity(print_unique.len());
+                            for pos in std::mem::take(&mut print_unique).into_iter().rev() {
+                                if !print_vec_deduped.contains(&pos) {
+                                    print_vec_deduped.push(pos);
+                                }
+                            }
+                            print_unique = print_vec_deduped.into_iter().rev().collect();
+
+                            let mut rotated_guard = guard;
+                            rotated_guard.rotate();
+
+                            self.print_map(
+                                &rotated_guard,
+                                print_unique.iter().cloned(),
+                                Some(next_guard.pos()),
+                                spec_unique_print.into_iter(),
+                            );
+                        }
+
+                        // New tile is unique, insert and set as the new val
+                        unique.insert(insert_pos, next_guard);
+                        guard = next_guard;
+
+                        #[cfg(feature = "print")]
+                        print_unique.push(next_guard.pos());
+                    }
+                } else {
+                    // New tile is not unique, set as the new val
+                    guard = next_guard;
+
+                    #[cfg(feature = "print")]
+                    print_unique.push(next_guard.pos());
+                }
+            } else {
+                break;
+            }
+        }
+
+        num_blocks
+    }
+
+    /// Debug print
+    #[cfg(feature = "print")]
+    pub fn print_map<I0, I1>(
+        &self,
+        guard: &Guard,
+        unique: I0,
+        blockage: Option<Pos2D>,
+        speculative: I1,
+    ) where
+        I0: ExactSizeIterator<Item = Pos2D>,
+        I1: ExactSizeIterator<Item = Pos2D>,
+    {
+        use advent_rust_lib::render::{Canvas, Gradient, Style};
+
+        let mut canvas = Canvas::new(self.max_x, self.max_y).spaced(1);
+
+        // Skip the darkest part of the gradient, so the start is visible.
+        // Returns the first color used.
+        let overlay = |canvas: &mut Canvas, path: Vec<Pos2D>, glyph| {
+            let skip = path.len() / 5;
+            let colors: Vec<_> = Gradient::Turbo
+                .colors(path.len() + skip)
+                .skip(skip)
+                .collect();
+            for (pos, color) in path.into_iter().zip(colors.iter()) {
+                canvas.set(pos, Style::new(glyph).fg(*color));
+            }
+            colors.first().copied()
+        };
+        let start_color = overlay(&mut canvas, unique.collect(), '•');
+        overlay(&mut canvas, speculative.collect(), '$');
+
+        if let Some(color) = start_color {
+            canvas.set(self.guard.pos(), Style::new('^').fg(color));
+        }
+
+        let guard_char = match guard.dir() {
+            GuardDir::Up => '^',
+            GuardDir::Right => '>',
+            GuardDir::Down => 'v',
+            GuardDir::Left => '<',
+        };
+        canvas.set(guard.pos(), Style::new(guard_char));
+        if let Some(blockage) = blockage {
+            canvas.set(blockage, Style::new('O'));
+        }
+
+        self.obstacles.iter().for_each(|(y, x_vals)| {
+            x_vals.iter().for_each(|x| {
+                canvas.set(Pos2D::new(*x, *y), Style::new('#'));
+            })
+        });
+
+        println!();
+        println!("{}", "--".repeat(self.max_x));
+        canvas
+            .write_ansi(&mut std::io::stdout())
+            .expect("Failed to write to stdout");
+        println!("{}", "--".repeat(self.max_x));
+    }
+}
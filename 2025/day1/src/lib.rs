@@ -0,0 +1,68 @@
+use advent_rust_lib::solution::{Params, Solution, SolutionResult};
+
+fn rotations(input: &str) -> impl Iterator<Item = i64> + use<'_> {
+    input.lines().map(|line| {
+        let num: i64 = str::parse(&line[1..]).unwrap();
+        match line.chars().next().unwrap() {
+            'R' => num,
+            'L' => -num,
+            x => panic!("Input WTF: {x}"),
+        }
+    })
+}
+
+fn part1<I: IntoIterator<Item = i64>>(rotations: I) -> u64 {
+    let mut pos = 50;
+    let mut zeroes = 0;
+
+    for rot in rotations {
+        pos = (pos + rot) % 100;
+        // Not really necessary but I like the branchless trick
+        // Rust bools are always 1 if true.
+        zeroes += (pos == 0) as u64;
+    }
+
+    zeroes
+}
+
+fn part2<I: IntoIterator<Item = i64>>(rotations: I) -> i64 {
+    let mut pos = 50;
+    let mut zeroes = 0;
+
+    for rot in rotations {
+        let new_value = pos + rot;
+        // Signum used to capture new_value == 0 as a switch
+        let switched_direction = (pos != 0) && (new_value.signum() != pos.signum());
+
+        // Captures forward zero crosses
+        zeroes += (new_value).abs() / 100;
+        // Captures a backwards zero cross
+        // Remaining backwards motion is equivalent to forward
+        zeroes += (switched_direction) as i64;
+
+        pos = new_value % 100;
+    }
+
+    zeroes
+}
+
+// Around 1.2 ms on my system with my input.
+pub struct Day1;
+
+impl Solution for Day1 {
+    fn year(&self) -> u16 {
+        2025
+    }
+
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn part_1(&self, input: &str, _params: &Params) -> SolutionResult {
+        Ok(part1(rotations(input)).to_string())
+    }
+
+    fn part_2(&self, input: &str, _params: &Params) -> SolutionResult {
+        Ok(part2(rotations(input)).to_string())
+    }
+}
@@ -0,0 +1,83 @@
+use std::num::ParseIntError;
+
+use advent_rust_lib::{
+    interval::IntervalSet,
+    solution::{Params, Solution, SolutionResult},
+};
+
+fn is_single_repeated(id: u64) -> bool {
+    let num_digits = ((id as f32).log10() as u32) + 1;
+    // Splits digits evenly in half
+    let div = 10_u64.pow(num_digits / 2);
+
+    // Div gets top, mod gets bottom
+    (id / div) == (id % div)
+}
+
+// Keeping as digits instead of string digit transforms should be cheaper.
+fn is_at_least_one_repeated(id: u64) -> bool {
+    let num_digits = ((id as f32).log10() as u32) + 1;
+
+    // Moving digit split
+    (1..=(num_digits / 2))
+        // Fully repeated sequences require even division
+        .filter(|cut| num_digits.is_multiple_of(*cut))
+        .any(|cut| {
+            let div = 10_u64.pow(cut);
+
+            // Check that all N slices of the number have the sequence
+            let mut rolling = id;
+            let val = id % div;
+            (1..(num_digits / cut)).all(|_rep| {
+                rolling /= div;
+                (rolling % div) == val
+            })
+        })
+}
+
+fn part1(input: &IntervalSet<u64>) -> u64 {
+    input
+        .intervals()
+        .flatten()
+        .filter(|id| is_single_repeated(*id))
+        .sum()
+}
+
+fn part2(input: &IntervalSet<u64>) -> u64 {
+    input
+        .intervals()
+        .flatten()
+        .filter(|id| is_at_least_one_repeated(*id))
+        .sum()
+}
+
+fn id_ranges(input: &str) -> Result<IntervalSet<u64>, ParseIntError> {
+    let input_line = input.lines().next().unwrap_or_default();
+
+    let mut ranges = IntervalSet::new();
+    for (start, end) in input_line.split(",").flat_map(|pair| pair.split_once('-')) {
+        ranges.insert_inclusive(start.parse()?..=end.parse()?);
+    }
+    Ok(ranges)
+}
+
+// About 149 ms execution on my machine.
+pub struct Day2;
+
+impl Solution for Day2 {
+    fn year(&self) -> u16 {
+        2025
+    }
+
+    fn day(&self) -> u8 {
+        2
+    }
+
+    fn part_1(&self, input: &str, _params: &Params) -> SolutionResult {
+        Ok(part1(&id_ranges(input)?).to_string())
+    }
+
+    fn part_2(&self, input: &str, _params: &Params) -> SolutionResult {
+        Ok(part2(&id_ranges(input)?).to_string())
+    }
+}
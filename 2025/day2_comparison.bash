@@ -1,3 +1,3 @@
 #!/usr/bin/env bash
 
-hyperfine -N -w 100 "day2/target/release/day2 day2/input.txt" "day2_opt/target/release/day2_opt day2/input.txt"
+hyperfine -N -w 100 "../target/release/aoc-2025-day2 day2/input.txt" "day2_opt/target/release/day2_opt day2/input.txt"
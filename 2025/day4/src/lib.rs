@@ -0,0 +1,50 @@
+use advent_rust_lib::{
+    automaton::Automaton,
+    bit_grid::BitGrid,
+    grid::NonRectangleInput,
+    solution::{Params, Solution, SolutionResult},
+};
+
+fn part1(grid: &BitGrid) -> u64 {
+    let accessible = &grid.neighbor_counts().less_than(4) & grid;
+    accessible.count_ones() as u64
+}
+
+fn part2(grid: &BitGrid) -> u64 {
+    // Rolls stay while they have at least four neighboring rolls
+    let mut forklift = Automaton::new(
+        grid.clone(),
+        |roll| *roll,
+        |roll, count| *roll && count >= 4,
+    );
+
+    forklift
+        .generations()
+        .map(|removed| removed.len() as u64)
+        .sum()
+}
+
+fn grid(input: &str) -> Result<BitGrid, NonRectangleInput> {
+    let (grid, _) = BitGrid::parse_chars(input, &[], |c| c == '@')?;
+    Ok(grid)
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    fn year(&self) -> u16 {
+        2025
+    }
+
+    fn day(&self) -> u8 {
+        4
+    }
+
+    fn part_1(&self, input: &str, _params: &Params) -> SolutionResult {
+        Ok(part1(&grid(input)?).to_string())
+    }
+
+    fn part_2(&self, input: &str, _params: &Params) -> SolutionResult {
+        Ok(part2(&grid(input)?).to_string())
+    }
+}
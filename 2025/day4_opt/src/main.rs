@@ -1,5 +1,3 @@
-use std::{cmp::Ordering, collections::HashMap};
-
 use advent_rust_lib::{
     grid::{Pos2D, RectangleGrid},
     read::input,
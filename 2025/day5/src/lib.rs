@@ -0,0 +1,46 @@
+use advent_rust_lib::{
+    interval::IntervalSet,
+    parse::{ParseError, sections},
+    solution::{Params, Solution, SolutionResult},
+};
+
+/// Returns (fresh ranges, ingredients).
+fn parse(input: &str) -> Result<(IntervalSet<u64>, Vec<u64>), ParseError> {
+    let mut input = sections(input);
+    let mut next_lines = || input.next().into_iter().flat_map(|section| section.lines());
+
+    let mut fresh_ranges = IntervalSet::new();
+    for line in next_lines() {
+        let [start, end] = line.int_array()?;
+        fresh_ranges.insert_inclusive(start..=end);
+    }
+
+    let ingredients = next_lines()
+        .map(|line| line.parse())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((fresh_ranges, ingredients))
+}
+
+/// Executes in about 940 microseconds on my machine.
+pub struct Day5;
+
+impl Solution for Day5 {
+    fn year(&self) -> u16 {
+        2025
+    }
+
+    fn day(&self) -> u8 {
+        5
+    }
+
+    fn part_1(&self, input: &str, _params: &Params) -> SolutionResult {
+        let (fresh_ranges, ingredients) = parse(input)?;
+        Ok(fresh_ranges.count_contained(ingredients).to_string())
+    }
+
+    fn part_2(&self, input: &str, _params: &Params) -> SolutionResult {
+        let (fresh_ranges, _) = parse(input)?;
+        Ok(fresh_ranges.total_len().to_string())
+    }
+}
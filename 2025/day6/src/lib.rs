@@ -0,0 +1,117 @@
+use std::ops::{Add, Mul};
+
+use advent_rust_lib::{
+    grid::RectangleGrid,
+    solution::{Params, Solution, SolutionResult},
+};
+
+#[derive(Debug, Clone)]
+pub struct Problem {
+    items: Vec<u64>,
+    op: fn(u64, u64) -> u64,
+}
+
+impl Problem {
+    fn resolve(&self) -> u64 {
+        self.items.iter().copied().reduce(self.op).unwrap_or(0)
+    }
+}
+
+fn parse_input(input: &str) -> Vec<Problem> {
+    let mut input = input.lines();
+    if let Some(first_line) = input.next() {
+        let mut problems: Vec<_> = first_line
+            .split_whitespace()
+            .map(|item| Problem {
+                items: vec![item.parse().unwrap()],
+                op: u64::add,
+            })
+            .collect();
+
+        for line in input {
+            problems.iter_mut().zip(line.split_whitespace()).for_each(
+                |(problem, item)| match item {
+                    "*" => problem.op = u64::mul,
+                    "+" => problem.op = u64::add,
+                    x => {
+                        if let Ok(num) = x.parse() {
+                            problem.items.push(num)
+                        } else {
+                            panic!("WTF: {x}")
+                        }
+                    }
+                },
+            );
+        }
+
+        problems
+    } else {
+        Vec::new()
+    }
+}
+
+fn parse_rot_input(input: &str) -> Vec<Problem> {
+    let input: Vec<_> = input.lines().collect();
+    if let Some((operators, rest)) = input.split_last() {
+        let operators = operators.split_whitespace().map(|item| match item {
+            "*" => u64::mul,
+            "+" => u64::add,
+            x => {
+                panic!("WTF: {x}")
+            }
+        });
+
+        // Pad short lines, so every column is read top to bottom
+        let width = rest.iter().map(|line| line.len()).max().unwrap_or(0);
+        let grid = RectangleGrid::try_from_iter_strict(
+            rest.iter()
+                .map(|line| format!("{line:width$}").chars().collect::<Vec<_>>()),
+        )
+        .unwrap();
+
+        let rotated: Vec<String> = grid.columns().map(|column| column.collect()).collect();
+
+        let rotated_chunks = rotated.split(|line| line.trim().is_empty());
+
+        rotated_chunks
+            .zip(operators)
+            .map(|(line, op)| Problem {
+                items: line
+                    .iter()
+                    .map(|item| item.trim().parse::<u64>().unwrap())
+                    .collect(),
+                op,
+            })
+            .collect()
+    } else {
+        Vec::new()
+    }
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    fn year(&self) -> u16 {
+        2025
+    }
+
+    fn day(&self) -> u8 {
+        6
+    }
+
+    fn part_1(&self, input: &str, _params: &Params) -> SolutionResult {
+        Ok(parse_input(input)
+            .into_iter()
+            .map(|problem| problem.resolve())
+            .sum::<u64>()
+            .to_string())
+    }
+
+    fn part_2(&self, input: &str, _params: &Params) -> SolutionResult {
+        Ok(parse_rot_input(input)
+            .into_iter()
+            .map(|problem| problem.resolve())
+            .sum::<u64>()
+            .to_string())
+    }
+}
@@ -0,0 +1,72 @@
+use std::array;
+
+use advent_rust_lib::{
+    kd_tree::KdTree,
+    mst::kruskal_points,
+    posn::{Pos, SquaredEuclid},
+    solution::{Params, Solution, SolutionError, SolutionResult},
+};
+
+type Pos3D = Pos<u64, 3>;
+
+fn boxes(input: &str) -> Vec<Pos3D> {
+    input
+        .lines()
+        .map(|line| {
+            let mut parts = line.split(',').map(|num| num.parse().unwrap());
+            Pos3D::new(array::from_fn(|_| parts.by_ref().next().unwrap()))
+        })
+        .collect()
+}
+
+/// Product of the three largest circuits after considering the `reps`
+/// closest pairs.
+fn part1(boxes: &[Pos3D], reps: usize) -> u64 {
+    let tree = KdTree::new(boxes.iter().copied());
+    // Connects the closest pairs first, with every box starting as its own
+    // circuit
+    let mut circuits = kruskal_points::<_, 3, SquaredEuclid>(&tree);
+    circuits.consider(reps);
+
+    let mut lengths: Vec<_> = circuits.sets().component_sizes().collect();
+    lengths.sort_unstable();
+
+    lengths
+        .iter()
+        .rev()
+        .take(3)
+        .map(|len| *len as u64)
+        .product()
+}
+
+/// Product of the x coordinates of the pair that joins every box into a
+/// single circuit.
+fn part2(boxes: &[Pos3D]) -> Option<u64> {
+    let tree = KdTree::new(boxes.iter().copied());
+    kruskal_points::<_, 3, SquaredEuclid>(&tree)
+        .last()
+        .map(|step| boxes[step.edge.lhs].coordinates[0] * boxes[step.edge.rhs].coordinates[0])
+}
+
+/// Takes the number of pairs to consider for part 1 as `--reps`.
+pub struct Day8;
+
+impl Solution for Day8 {
+    fn year(&self) -> u16 {
+        2025
+    }
+
+    fn day(&self) -> u8 {
+        8
+    }
+
+    fn part_1(&self, input: &str, params: &Params) -> SolutionResult {
+        Ok(part1(&boxes(input), params.get("reps")?).to_string())
+    }
+
+    fn part_2(&self, input: &str, _params: &Params) -> SolutionResult {
+        Ok(part2(&boxes(input))
+            .ok_or(SolutionError::from("Boxes never form a single circuit"))?
+            .to_string())
+    }
+}
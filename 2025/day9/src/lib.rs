@@ -0,0 +1,80 @@
+use advent_rust_lib::{
+    grid_ext::Pos2DExt,
+    polygon::Polygon,
+    posn::Pos,
+    solution::{Params, Solution, SolutionResult},
+};
+
+type Pos2D = Pos<u64, 2>;
+
+fn red_tiles(input: &str) -> Vec<Pos2D> {
+    input
+        .lines()
+        .map(|line| {
+            let (x, y) = line.split_once(',').unwrap();
+            Pos2D::new([x.parse().unwrap(), y.parse().unwrap()])
+        })
+        .collect()
+}
+
+fn part1(red_tiles: &[Pos2D]) -> u64 {
+    red_tiles
+        .iter()
+        .flat_map(|lhs| {
+            red_tiles.iter().map(move |rhs| {
+                let diff = lhs.abs_diff(rhs);
+                let fluffed = Pos2D::new(diff.coordinates.map(|dim| dim + 1));
+                fluffed.area()
+            })
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+/// Largest rectangle between red tiles that only covers red or green tiles.
+///
+/// The red tiles are the corners of a rectilinear loop, and green tiles are
+/// on and inside that loop.
+fn part2(red_tiles: &[Pos2D]) -> u64 {
+    let corners: Vec<_> = red_tiles
+        .iter()
+        .map(|tile| Pos2DExt::new(tile.coordinates[0] as i64, tile.coordinates[1] as i64))
+        .collect();
+    let tiles = Polygon::new(corners.clone()).tiles();
+
+    let mut rectangles: Vec<_> = corners
+        .iter()
+        .enumerate()
+        .flat_map(|(idx, lhs)| corners[idx + 1..].iter().map(move |rhs| (*lhs, *rhs)))
+        .map(|(lhs, rhs)| {
+            let area = (lhs.x.abs_diff(rhs.x) + 1) * (lhs.y.abs_diff(rhs.y) + 1);
+            (area, lhs, rhs)
+        })
+        .collect();
+    rectangles.sort_unstable_by_key(|(area, _, _)| std::cmp::Reverse(*area));
+
+    rectangles
+        .into_iter()
+        .find(|(_, lhs, rhs)| tiles.contains_rect(*lhs, *rhs))
+        .map_or(0, |(area, _, _)| area)
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    fn year(&self) -> u16 {
+        2025
+    }
+
+    fn day(&self) -> u8 {
+        9
+    }
+
+    fn part_1(&self, input: &str, _params: &Params) -> SolutionResult {
+        Ok(part1(&red_tiles(input)).to_string())
+    }
+
+    fn part_2(&self, input: &str, _params: &Params) -> SolutionResult {
+        Ok(part2(&red_tiles(input)).to_string())
+    }
+}
@@ -0,0 +1,104 @@
+use std::{env::args, process::exit};
+
+use advent_rust_lib::solution::{run, RunArgs, Solution, SolutionError};
+
+const USAGE: &str = "\
+Usage:
+    aoc run <year> <day> [--input FILE]... [--part 1|2] [--<name> <value>]...
+    aoc list
+
+Input is read from stdin when no --input is given.";
+
+/// Every day that implements [`Solution`], in chronological order.
+fn solutions() -> Vec<&'static dyn Solution> {
+    vec![
+        &aoc_2023_day1::Day1,
+        &aoc_2023_day2::Day2,
+        &aoc_2023_day3::Day3,
+        &aoc_2023_day4::Day4,
+        &aoc_2023_day5::Day5,
+        &aoc_2023_day6::Day6,
+        &aoc_2023_day7::Day7,
+        &aoc_2023_day8::Day8,
+        &aoc_2023_day9::Day9,
+        &aoc_2024_day1::Day1,
+        &aoc_2024_day2::Day2,
+        &aoc_2024_day3::Day3,
+        &aoc_2024_day4::Day4,
+        &aoc_2024_day5::Day5,
+        &aoc_2024_day6::Day6,
+        &aoc_2024_day7::Day7,
+        &aoc_2024_day8::Day8,
+        &aoc_2024_day9::Day9,
+        &aoc_2024_day10::Day10,
+        &aoc_2024_day11::Day11,
+        &aoc_2024_day12::Day12,
+        &aoc_2024_day13::Day13,
+        &aoc_2024_day14::Day14,
+        &aoc_2024_day15::Day15,
+        &aoc_2024_day16::Day16,
+        &aoc_2024_day18::Day18,
+        &aoc_2024_day19::Day19,
+        &aoc_2025_day1::Day1,
+        &aoc_2025_day2::Day2,
+        &aoc_2025_day3::Day3,
+        &aoc_2025_day4::Day4,
+        &aoc_2025_day5::Day5,
+        &aoc_2025_day6::Day6,
+        &aoc_2025_day7::Day7,
+        &aoc_2025_day8::Day8,
+        &aoc_2025_day9::Day9,
+    ]
+}
+
+fn find(year: u16, day: u8) -> Option<&'static dyn Solution> {
+    solutions()
+        .into_iter()
+        .find(|solution| solution.year() == year && solution.day() == day)
+}
+
+fn run_day(mut args: impl Iterator<Item = String>) -> Result<(), SolutionError> {
+    let year: u16 = args.next().ok_or("Missing year")?.parse()?;
+    let day: u8 = args.next().ok_or("Missing day")?.parse()?;
+    let solution = find(year, day).ok_or_else(|| format!("No solution for {year} day {day}"))?;
+
+    run(solution, &RunArgs::parse(args)?)
+}
+
+fn main() {
+    let mut args = args().skip(1);
+
+    let res = match args.next().as_deref() {
+        Some("run") => run_day(args),
+        Some("list") => {
+            for solution in solutions() {
+                println!("{} {}", solution.year(), solution.day());
+            }
+            Ok(())
+        }
+        _ => {
+            eprintln!("{USAGE}");
+            exit(2);
+        }
+    };
+
+    if let Err(e) = res {
+        eprintln!("{e}");
+        exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unique_days() {
+        let days: Vec<_> = solutions()
+            .into_iter()
+            .map(|solution| (solution.year(), solution.day()))
+            .collect();
+
+        assert!(days.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+}
@@ -0,0 +1,111 @@
+//! Derive macros for `advent-rust-lib`.
+//!
+//! Use these through the re-exports in `advent-rust-lib`, which the
+//! generated code refers to.
+
+use std::collections::HashMap;
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{
+    parse_macro_input, punctuated::Punctuated, spanned::Spanned, Data, DeriveInput, Fields,
+    LitChar, Token,
+};
+
+/// Maps each unit variant of an enum to and from characters.
+///
+/// Every variant needs a `#[cell('c', ...)]` attribute. The first character
+/// is used when printing, and all of them are accepted when parsing.
+///
+/// Implements `GridCell`, `TryFrom<char>` and `Display`.
+#[proc_macro_derive(GridCell, attributes(cell))]
+pub fn derive_grid_cell(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    grid_cell(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn grid_cell(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "GridCell can only be derived for enums",
+        ));
+    };
+
+    let mut seen = HashMap::new();
+    let mut parse_arms = Vec::new();
+    let mut print_arms = Vec::new();
+
+    for variant in &data.variants {
+        let ident = &variant.ident;
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new(
+                variant.fields.span(),
+                "GridCell variants cannot hold data",
+            ));
+        }
+
+        let attr = variant
+            .attrs
+            .iter()
+            .find(|attr| attr.path().is_ident("cell"))
+            .ok_or_else(|| syn::Error::new(ident.span(), "Missing #[cell('c')] attribute"))?;
+        let chars = attr.parse_args_with(Punctuated::<LitChar, Token![,]>::parse_terminated)?;
+
+        let printed = chars
+            .first()
+            .ok_or_else(|| syn::Error::new(attr.span(), "#[cell] needs at least one character"))?;
+        print_arms.push(quote! { Self::#ident => #printed });
+
+        for c in &chars {
+            if let Some(other) = seen.insert(c.value(), ident) {
+                return Err(syn::Error::new(
+                    c.span(),
+                    format!("{:?} is already used by {other}", c.value()),
+                ));
+            }
+        }
+        let chars = chars.iter();
+        parse_arms.push(quote! { #(#chars)|* => ::std::option::Option::Some(Self::#ident) });
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::advent_rust_lib::grid::GridCell for #name #ty_generics #where_clause {
+            fn from_char(c: char) -> ::std::option::Option<Self> {
+                match c {
+                    #(#parse_arms,)*
+                    _ => ::std::option::Option::None,
+                }
+            }
+
+            fn to_char(&self) -> char {
+                match *self {
+                    #(#print_arms,)*
+                }
+            }
+        }
+
+        impl #impl_generics ::std::convert::TryFrom<char> for #name #ty_generics #where_clause {
+            type Error = ::advent_rust_lib::grid::UnknownCell;
+
+            fn try_from(c: char) -> ::std::result::Result<Self, Self::Error> {
+                <Self as ::advent_rust_lib::grid::GridCell>::from_char(c)
+                    .ok_or(::advent_rust_lib::grid::UnknownCell(c))
+            }
+        }
+
+        impl #impl_generics ::std::fmt::Display for #name #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                ::std::fmt::Write::write_char(
+                    f,
+                    <Self as ::advent_rust_lib::grid::GridCell>::to_char(self),
+                )
+            }
+        }
+    })
+}
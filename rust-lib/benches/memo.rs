@@ -0,0 +1,38 @@
+use advent_rust_lib::memo::{Cache, Memo};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+const TOWELS: [&str; 8] = ["r", "wr", "b", "g", "bwu", "rb", "gb", "br"];
+
+/// Ways to build `design`, as in 2024 day 19.
+fn arrangements<C: Cache<usize, u64>>(mut memo: Memo<usize, u64, C>, design: &str) -> u64 {
+    memo.solve(0, &|recurse, start: &usize| {
+        if *start == design.len() {
+            return 1;
+        }
+        TOWELS
+            .iter()
+            .filter(|towel| design[*start..].starts_with(**towel))
+            .map(|towel| recurse(start + towel.len()))
+            .sum()
+    })
+}
+
+pub fn caches(c: &mut Criterion) {
+    let mut group = c.benchmark_group("towel_arrangements");
+    let design = "brwrrbggbrgrbwurbbrgbwrrbgbwurgbrrwrbgbrbwurrbgg".repeat(4);
+
+    group.bench_function("hash", |b| b.iter(|| arrangements(Memo::new(), &design)));
+
+    group.bench_function("bounded", |b| {
+        b.iter(|| arrangements(Memo::bounded(16), &design))
+    });
+
+    group.bench_function("dense", |b| {
+        b.iter(|| arrangements(Memo::dense(design.len() + 1), &design))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, caches);
+criterion_main!(benches);
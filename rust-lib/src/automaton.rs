@@ -0,0 +1,222 @@
+//! Cellular automata on any [`GridMut`].
+//!
+//! ```
+//! use advent_rust_lib::{automaton::Automaton, bit_grid::BitGrid};
+//!
+//! let (rolls, _) = BitGrid::parse_chars("..@@.\n@@@.@\n..@..", &[], |c| c == '@').unwrap();
+//!
+//! // Remove rolls with fewer than four neighboring rolls, until none are left to remove
+//! let mut forklift = Automaton::new(rolls, |roll| *roll, |roll, count| *roll && count >= 4);
+//! let removed: Vec<usize> = forklift.generations().map(|changed| changed.len()).collect();
+//!
+//! assert_eq!(removed, [5, 2]);
+//! assert!(forklift.is_fixed_point());
+//! ```
+
+use std::collections::HashSet;
+
+use crate::grid::{Grid, GridMut};
+
+/// Cells around each cell that are counted.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Neighborhood {
+    /// Up, down, left and right.
+    Orthogonal,
+    /// Orthogonal and diagonal.
+    #[default]
+    Surrounding,
+}
+
+/// Steps every cell by a rule over the cell and how many of its neighbors
+/// are counted.
+///
+/// Cells outside the grid are never counted.
+#[derive(Debug, Clone)]
+pub struct Automaton<G: Grid, C, R> {
+    grid: G,
+    neighborhood: Neighborhood,
+    counted: C,
+    rule: R,
+    generation: usize,
+    // Cells that may change on the next worklist step, or None for every cell
+    worklist: Option<HashSet<G::Pos>>,
+}
+
+impl<G, C, R> Automaton<G, C, R>
+where
+    G: GridMut,
+    G::Cell: PartialEq,
+    G::Pos: Ord,
+    C: Fn(&G::Cell) -> bool,
+    R: FnMut(&G::Cell, u8) -> G::Cell,
+{
+    /// `counted` picks the neighbors to count, and `rule` gives the next
+    /// value of a cell from its current value and that count.
+    pub fn new(grid: G, counted: C, rule: R) -> Self {
+        Self {
+            grid,
+            neighborhood: Neighborhood::default(),
+            counted,
+            rule,
+            generation: 0,
+            worklist: None,
+        }
+    }
+
+    pub fn with_neighborhood(mut self, neighborhood: Neighborhood) -> Self {
+        self.neighborhood = neighborhood;
+        self.worklist = None;
+        self
+    }
+
+    pub fn grid(&self) -> &G {
+        &self.grid
+    }
+
+    pub fn into_grid(self) -> G {
+        self.grid
+    }
+
+    /// Number of steps taken.
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// True once a step changed nothing.
+    pub fn is_fixed_point(&self) -> bool {
+        self.worklist.as_ref().is_some_and(HashSet::is_empty)
+    }
+
+    fn neighbors(&self, pos: G::Pos) -> Box<dyn Iterator<Item = G::Pos> + '_> {
+        match self.neighborhood {
+            Neighborhood::Orthogonal => Box::new(self.grid.neighbors4(pos)),
+            Neighborhood::Surrounding => Box::new(self.grid.neighbors8(pos)),
+        }
+    }
+
+    /// Number of counted neighbors around `pos`.
+    pub fn count(&self, pos: G::Pos) -> u8 {
+        self.neighbors(pos)
+            .filter(|next| self.grid.get(*next).is_some_and(&self.counted))
+            .count() as u8
+    }
+
+    /// Applies the rule to each of `candidates` at once, returning the
+    /// positions that changed in order.
+    fn evaluate<I: IntoIterator<Item = G::Pos>>(&mut self, candidates: I) -> Vec<G::Pos> {
+        let mut updates = Vec::new();
+        for pos in candidates {
+            let count = self.count(pos);
+            if let Some(cell) = self.grid.get(pos) {
+                let next = (self.rule)(cell, count);
+                if next != *cell {
+                    updates.push((pos, next));
+                }
+            }
+        }
+
+        let mut changed = Vec::with_capacity(updates.len());
+        let mut worklist = HashSet::new();
+        for (pos, next) in updates {
+            self.grid.replace(pos, next);
+            changed.push(pos);
+            worklist.insert(pos);
+        }
+        for pos in &changed {
+            worklist.extend(self.neighbors(*pos));
+        }
+        changed.sort_unstable();
+
+        self.worklist = Some(worklist);
+        self.generation += 1;
+        changed
+    }
+
+    /// Steps every cell, returning the positions that changed in order.
+    pub fn step(&mut self) -> Vec<G::Pos> {
+        let candidates: Vec<_> = self.grid.positions().collect();
+        self.evaluate(candidates)
+    }
+
+    /// Same result as [`Self::step`], but only evaluates the cells around
+    /// those that changed in the previous step.
+    pub fn step_changed(&mut self) -> Vec<G::Pos> {
+        match self.worklist.take() {
+            Some(worklist) => self.evaluate(worklist),
+            None => self.step(),
+        }
+    }
+
+    /// Positions changed by each worklist step, until a fixed point.
+    pub fn generations(&mut self) -> impl Iterator<Item = Vec<G::Pos>> + '_ {
+        std::iter::from_fn(move || {
+            let changed = self.step_changed();
+            (!changed.is_empty()).then_some(changed)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{bit_grid::BitGrid, grid::RectangleGrid};
+
+    use super::*;
+
+    fn life(lines: &[&str]) -> RectangleGrid<bool> {
+        RectangleGrid::try_from_iter_strict(
+            lines
+                .iter()
+                .map(|line| line.chars().map(|c| c == '#').collect::<Vec<_>>()),
+        )
+        .unwrap()
+    }
+
+    fn conway(alive: &bool, count: u8) -> bool {
+        count == 3 || (*alive && count == 2)
+    }
+
+    #[test]
+    fn worklist_matches_full_steps() {
+        let start = life(&[".....", "..#..", "...#.", ".###.", "....."]);
+        let mut full = Automaton::new(start.clone(), |alive| *alive, conway);
+        let mut worklist = Automaton::new(start, |alive| *alive, conway);
+
+        for _ in 0..6 {
+            assert_eq!(full.step(), worklist.step_changed());
+            assert!(full.grid().items().eq(worklist.grid().items()));
+        }
+        assert_eq!(worklist.generation(), 6);
+    }
+
+    #[test]
+    fn bit_grid_matches_rectangle_grid() {
+        let start = life(&["......", ".##...", ".##...", "...##.", "...##.", "......"]);
+        let mut rect = Automaton::new(start.clone(), |alive| *alive, conway);
+        let mut bits = Automaton::new(BitGrid::from(&start), |alive| *alive, conway);
+
+        for _ in 0..4 {
+            assert_eq!(rect.step_changed(), bits.step_changed());
+        }
+        assert!(rect
+            .grid()
+            .positions()
+            .all(|pos| rect.grid().at(pos) == bits.grid().at(pos)));
+    }
+
+    #[test]
+    fn fixed_points() {
+        let blinker = life(&[".....", "..#..", "..#..", "..#..", "....."]);
+        let mut automaton = Automaton::new(blinker, |alive| *alive, conway);
+        assert_eq!(automaton.generations().take(10).count(), 10);
+        assert!(!automaton.is_fixed_point());
+
+        // Orthogonal neighbors: each end only has one, and dies
+        let line = life(&["###"]);
+        let mut automaton =
+            Automaton::new(line, |alive| *alive, |alive, count| *alive && count >= 2)
+                .with_neighborhood(Neighborhood::Orthogonal);
+        assert_eq!(automaton.generations().count(), 2);
+        assert!(automaton.is_fixed_point());
+        assert!(automaton.grid().items().all(|alive| !alive));
+    }
+}
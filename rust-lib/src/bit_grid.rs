@@ -0,0 +1,523 @@
+//! Boolean grid packed into 64-bit words.
+//!
+//! ```
+//! use advent_rust_lib::{bit_grid::BitGrid, grid::Pos2D};
+//!
+//! let (rolls, _) = BitGrid::parse_chars("..@@.\n@@@.@\n..@..", &[], |c| c == '@').unwrap();
+//!
+//! // Rolls with fewer than four neighboring rolls
+//! let accessible = &rolls.neighbor_counts().less_than(4) & &rolls;
+//! assert_eq!(accessible.count_ones(), 5);
+//! assert_eq!(rolls.neighbor_count(Pos2D::new(2, 1)), Some(4));
+//! ```
+
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not};
+
+use crate::{
+    direction::Direction,
+    grid::{Grid, GridMut, Markers, NonRectangleInput, Pos2D, RectangleGrid},
+    iter::ArrayIter,
+};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// Number of set bits in `row` from `start` to `end`, inclusive.
+fn count_range(row: &[u64], start: usize, end: usize) -> u32 {
+    (start / WORD_BITS..=end / WORD_BITS)
+        .map(|idx| {
+            let low = if idx == start / WORD_BITS {
+                start % WORD_BITS
+            } else {
+                0
+            };
+            let high = if idx == end / WORD_BITS {
+                end % WORD_BITS
+            } else {
+                WORD_BITS - 1
+            };
+
+            let mask = (u64::MAX >> (WORD_BITS - 1 - high)) & (u64::MAX << low);
+            (row[idx] & mask).count_ones()
+        })
+        .sum()
+}
+
+/// Rectangle grid of booleans, one bit per cell.
+///
+/// Each line is packed into whole words, with x = 0 in the lowest bit of the
+/// first word. Bits past `x_max` are always clear.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    words: Box<[u64]>,
+    row_words: usize,
+    x_max: usize,
+    y_max: usize,
+}
+
+impl BitGrid {
+    /// Grid with every cell false.
+    pub fn new(x_max: usize, y_max: usize) -> Self {
+        let row_words = x_max.div_ceil(WORD_BITS);
+
+        Self {
+            words: vec![0; row_words * y_max].into_boxed_slice(),
+            row_words,
+            x_max,
+            y_max,
+        }
+    }
+
+    pub fn default_with_dim(x_max: usize, y_max: usize, default: bool) -> Self {
+        let mut grid = Self::new(x_max, y_max);
+        if default {
+            grid = !&grid;
+        }
+        grid
+    }
+
+    /// [`RectangleGrid::parse_chars`], packed into bits.
+    pub fn parse_chars<F>(
+        input: &str,
+        markers: &[char],
+        to_cell: F,
+    ) -> Result<(Self, Markers), NonRectangleInput>
+    where
+        F: FnMut(char) -> bool,
+    {
+        let (grid, markers) = RectangleGrid::parse_chars(input, markers, to_cell)?;
+        Ok((Self::from(&grid), markers))
+    }
+
+    pub fn x_max(&self) -> usize {
+        self.x_max
+    }
+
+    pub fn y_max(&self) -> usize {
+        self.y_max
+    }
+
+    /// Returns true if `pos` is within this grid's dimensions.
+    pub fn in_grid(&self, pos: Pos2D) -> bool {
+        (pos.y < self.y_max) && (pos.x < self.x_max)
+    }
+
+    /// Word index and bit mask of `pos`.
+    ///
+    /// Invalid if pos in not in this map.
+    fn bit(&self, pos: Pos2D) -> (usize, u64) {
+        (
+            (pos.y * self.row_words) + (pos.x / WORD_BITS),
+            1 << (pos.x % WORD_BITS),
+        )
+    }
+
+    /// Mask of the bits in use in the last word of each line.
+    fn last_word_mask(&self) -> u64 {
+        match self.x_max % WORD_BITS {
+            0 => u64::MAX,
+            used => (1 << used) - 1,
+        }
+    }
+
+    /// Clears the bits past `x_max`.
+    fn clear_padding(&mut self) {
+        let mask = self.last_word_mask();
+        if self.row_words > 0 {
+            self.words
+                .chunks_mut(self.row_words)
+                .for_each(|row| row[self.row_words - 1] &= mask);
+        }
+    }
+
+    /// Matches [`RectangleGrid::get`], for code generic over both.
+    pub fn get(&self, pos: Pos2D) -> Option<&bool> {
+        self.at(pos).map(|value| if value { &true } else { &false })
+    }
+
+    pub fn at(&self, pos: Pos2D) -> Option<bool> {
+        self.in_grid(pos).then(|| {
+            let (idx, mask) = self.bit(pos);
+            self.words[idx] & mask != 0
+        })
+    }
+
+    /// Returns the previous value, or `None` if `pos` is not in the grid.
+    pub fn set(&mut self, pos: Pos2D, value: bool) -> Option<bool> {
+        let previous = self.at(pos)?;
+
+        let (idx, mask) = self.bit(pos);
+        if value {
+            self.words[idx] |= mask;
+        } else {
+            self.words[idx] &= !mask;
+        }
+
+        Some(previous)
+    }
+
+    /// Each line as packed words.
+    pub fn lines(&self) -> impl ArrayIter<&[u64]> {
+        // Chunks of zero panic, and every line is empty anyway
+        let chunk = self.row_words.max(1);
+        self.words
+            .chunks(chunk)
+            .take(if self.row_words == 0 { 0 } else { self.y_max })
+    }
+
+    /// All positions, starting at top left and moving right before down.
+    pub fn positions(&self) -> impl ArrayIter<Pos2D> + use<> {
+        let (x_max, y_max) = (self.x_max, self.y_max);
+        (0..x_max * y_max).map(move |idx| Pos2D::new(idx % x_max, idx / x_max))
+    }
+
+    pub fn items(&self) -> impl ArrayIter<bool> + '_ {
+        self.positions().map(|pos| {
+            let (idx, mask) = self.bit(pos);
+            self.words[idx] & mask != 0
+        })
+    }
+
+    /// Number of true cells.
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Positions of true cells, in reading order.
+    pub fn ones(&self) -> impl Iterator<Item = Pos2D> + '_ {
+        self.words.iter().enumerate().flat_map(move |(idx, word)| {
+            let y = idx / self.row_words;
+            let x_start = (idx % self.row_words) * WORD_BITS;
+
+            let mut word = *word;
+            std::iter::from_fn(move || {
+                (word != 0).then(|| {
+                    let bit = word.trailing_zeros() as usize;
+                    word &= word - 1;
+                    Pos2D::new(x_start + bit, y)
+                })
+            })
+        })
+    }
+
+    /// Moves every cell one step in `dir`, dropping cells that leave the grid
+    /// and filling the gap with false.
+    pub fn shifted(&self, dir: Direction) -> Self {
+        let mut shifted = Self::new(self.x_max, self.y_max);
+        if self.row_words == 0 || self.y_max == 0 {
+            return shifted;
+        }
+
+        let row_words = self.row_words;
+        match dir {
+            Direction::Up => {
+                shifted.words[..(self.y_max - 1) * row_words]
+                    .copy_from_slice(&self.words[row_words..]);
+            }
+            Direction::Down => {
+                shifted.words[row_words..]
+                    .copy_from_slice(&self.words[..(self.y_max - 1) * row_words]);
+            }
+            Direction::Left => {
+                for (new, old) in shifted
+                    .words
+                    .chunks_mut(row_words)
+                    .zip(self.words.chunks(row_words))
+                {
+                    for idx in 0..row_words {
+                        let carry = old.get(idx + 1).map_or(0, |next| next << (WORD_BITS - 1));
+                        new[idx] = (old[idx] >> 1) | carry;
+                    }
+                }
+            }
+            Direction::Right => {
+                for (new, old) in shifted
+                    .words
+                    .chunks_mut(row_words)
+                    .zip(self.words.chunks(row_words))
+                {
+                    for idx in 0..row_words {
+                        let carry = idx
+                            .checked_sub(1)
+                            .map_or(0, |prev| old[prev] >> (WORD_BITS - 1));
+                        new[idx] = (old[idx] << 1) | carry;
+                    }
+                }
+                shifted.clear_padding();
+            }
+        }
+
+        shifted
+    }
+
+    /// Number of true cells among the 8 around `pos`.
+    pub fn neighbor_count(&self, pos: Pos2D) -> Option<u8> {
+        let center = self.at(pos)?;
+
+        let start = pos.x.saturating_sub(1);
+        let end = (pos.x + 1).min(self.x_max - 1);
+        let around: u32 = (pos.y.saturating_sub(1)..=(pos.y + 1).min(self.y_max - 1))
+            .map(|y| {
+                let row = &self.words[y * self.row_words..(y + 1) * self.row_words];
+                count_range(row, start, end)
+            })
+            .sum();
+
+        Some((around - center as u32) as u8)
+    }
+
+    /// Number of true cells among the 8 around every cell, counted a word at a
+    /// time.
+    pub fn neighbor_counts(&self) -> NeighborCounts {
+        let up = self.shifted(Direction::Up);
+        let down = self.shifted(Direction::Down);
+        let neighbors = [
+            up.shifted(Direction::Left),
+            up.shifted(Direction::Right),
+            down.shifted(Direction::Left),
+            down.shifted(Direction::Right),
+            self.shifted(Direction::Left),
+            self.shifted(Direction::Right),
+            up,
+            down,
+        ];
+
+        // Binary counter, one grid per bit of the count
+        let mut planes: [Self; 4] = std::array::from_fn(|_| Self::new(self.x_max, self.y_max));
+        for neighbor in neighbors {
+            let mut carry = neighbor;
+            for plane in &mut planes {
+                let sum = &*plane ^ &carry;
+                carry &= &*plane;
+                *plane = sum;
+            }
+        }
+
+        NeighborCounts { planes }
+    }
+
+    /// Unpacks into one `bool` per cell.
+    pub fn to_rectangle_grid(&self) -> RectangleGrid<bool> {
+        RectangleGrid::try_from_iter(
+            (0..self.y_max).map(|y| {
+                (0..self.x_max).map(move |x| self.at(Pos2D::new(x, y)).unwrap_or_default())
+            }),
+        )
+        .expect("every line has the same width")
+    }
+
+    /// Applies `op` to each pair of words.
+    ///
+    /// Panics if the grids have different dimensions.
+    fn zip_words_mut<F>(&mut self, rhs: &Self, mut op: F)
+    where
+        F: FnMut(&mut u64, u64),
+    {
+        assert_eq!(
+            (self.x_max, self.y_max),
+            (rhs.x_max, rhs.y_max),
+            "grids must have the same dimensions"
+        );
+        self.words
+            .iter_mut()
+            .zip(rhs.words.iter())
+            .for_each(|(lhs, rhs)| op(lhs, *rhs));
+    }
+}
+
+impl From<&RectangleGrid<bool>> for BitGrid {
+    fn from(grid: &RectangleGrid<bool>) -> Self {
+        let mut bits = Self::new(grid.x_max(), grid.y_max());
+        for entry in grid.positioned_items().filter(|entry| *entry.value) {
+            let (idx, mask) = bits.bit(entry.position());
+            bits.words[idx] |= mask;
+        }
+        bits
+    }
+}
+
+impl Grid for BitGrid {
+    type Cell = bool;
+    type Pos = Pos2D;
+
+    fn in_grid(&self, pos: Pos2D) -> bool {
+        BitGrid::in_grid(self, pos)
+    }
+
+    fn get(&self, pos: Pos2D) -> Option<&bool> {
+        BitGrid::get(self, pos)
+    }
+
+    fn positions(&self) -> impl Iterator<Item = Pos2D> + '_ {
+        BitGrid::positions(self)
+    }
+}
+
+impl GridMut for BitGrid {
+    fn replace(&mut self, pos: Pos2D, cell: bool) -> Option<bool> {
+        self.set(pos, cell)
+    }
+}
+
+macro_rules! impl_bit_op {
+    ($op:ident, $method:ident, $op_assign:ident, $method_assign:ident, $apply:tt) => {
+        impl $op_assign<&BitGrid> for BitGrid {
+            /// Panics if the grids have different dimensions.
+            fn $method_assign(&mut self, rhs: &BitGrid) {
+                self.zip_words_mut(rhs, |lhs, rhs| *lhs $apply rhs);
+            }
+        }
+
+        impl $op for &BitGrid {
+            type Output = BitGrid;
+
+            /// Panics if the grids have different dimensions.
+            fn $method(self, rhs: Self) -> BitGrid {
+                let mut out = self.clone();
+                out.$method_assign(rhs);
+                out
+            }
+        }
+    };
+}
+
+impl_bit_op!(BitAnd, bitand, BitAndAssign, bitand_assign, &=);
+impl_bit_op!(BitOr, bitor, BitOrAssign, bitor_assign, |=);
+impl_bit_op!(BitXor, bitxor, BitXorAssign, bitxor_assign, ^=);
+
+impl Not for &BitGrid {
+    type Output = BitGrid;
+
+    fn not(self) -> BitGrid {
+        let mut out = self.clone();
+        out.words.iter_mut().for_each(|word| *word = !*word);
+        out.clear_padding();
+        out
+    }
+}
+
+// -------------------------------------------------- //
+
+/// Neighbor count of every cell, from [`BitGrid::neighbor_counts`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NeighborCounts {
+    // Bit `n` of each count
+    planes: [BitGrid; 4],
+}
+
+impl NeighborCounts {
+    pub fn get(&self, pos: Pos2D) -> Option<u8> {
+        self.planes
+            .iter()
+            .enumerate()
+            .try_fold(0, |count, (bit, plane)| {
+                Some(count | ((plane.at(pos)? as u8) << bit))
+            })
+    }
+
+    /// Cells with exactly `count` neighbors.
+    pub fn equal_to(&self, count: u8) -> BitGrid {
+        let empty = BitGrid::new(self.planes[0].x_max, self.planes[0].y_max);
+        // No cell has more than 8 neighbors, and the planes only hold 4 bits
+        if count > 8 {
+            return empty;
+        }
+
+        let mut matching = !&empty;
+        for (bit, plane) in self.planes.iter().enumerate() {
+            if count & (1 << bit) != 0 {
+                matching &= plane;
+            } else {
+                matching &= &!plane;
+            }
+        }
+        matching
+    }
+
+    /// Cells with fewer than `count` neighbors.
+    pub fn less_than(&self, count: u8) -> BitGrid {
+        let mut matching = BitGrid::new(self.planes[0].x_max, self.planes[0].y_max);
+        for below in 0..count.min(9) {
+            matching |= &self.equal_to(below);
+        }
+        matching
+    }
+
+    /// Cells with `count` or more neighbors.
+    pub fn at_least(&self, count: u8) -> BitGrid {
+        !&self.less_than(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Irregular pattern over more than one word per line.
+    fn pattern() -> RectangleGrid<bool> {
+        RectangleGrid::try_from_iter(
+            (0..5).map(|y| (0..70).map(move |x: usize| (x * 7 + y * 3) % 5 < 2 || x == 63)),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn matches_rectangle_grid() {
+        let cells = pattern();
+        let mut bits = BitGrid::from(&cells);
+
+        assert_eq!(
+            bits.to_rectangle_grid().items().collect::<Vec<_>>(),
+            cells.items().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            bits.count_ones(),
+            cells.items().filter(|cell| **cell).count()
+        );
+        assert!(bits.ones().all(|pos| cells.at(pos) == Some(true)));
+        assert_eq!(bits.at(Pos2D::new(70, 0)), None);
+
+        assert_eq!(bits.set(Pos2D::new(65, 4), true), Some(false));
+        assert_eq!(bits.at(Pos2D::new(65, 4)), Some(true));
+        assert_eq!((!&bits).count_ones(), 70 * 5 - bits.count_ones());
+    }
+
+    #[test]
+    fn shifts() {
+        let cells = pattern();
+        let bits = BitGrid::from(&cells);
+
+        for dir in Direction::all() {
+            let shifted = bits.shifted(dir);
+            for pos in cells.positions() {
+                let source = pos.step_dir(dir.reverse());
+                let expected = source.and_then(|source| cells.at(source)).unwrap_or(false);
+                assert_eq!(shifted.at(pos), Some(expected), "{dir} at {pos}");
+            }
+        }
+    }
+
+    #[test]
+    fn neighbor_counts() {
+        let cells = pattern();
+        let bits = BitGrid::from(&cells);
+        let counts = bits.neighbor_counts();
+
+        for pos in cells.positions() {
+            let expected = pos
+                .surrounding_pos()
+                .filter(|around| cells.at(*around) == Some(true))
+                .count() as u8;
+            assert_eq!(counts.get(pos), Some(expected), "{pos}");
+            assert_eq!(bits.neighbor_count(pos), Some(expected), "{pos}");
+            assert_eq!(counts.less_than(4).at(pos), Some(expected < 4));
+        }
+
+        let empty = BitGrid::new(3, 3).neighbor_counts();
+        assert_eq!(empty.equal_to(0).count_ones(), 9);
+        assert_eq!(empty.equal_to(16).count_ones(), 0);
+        assert_eq!(empty.at_least(9).count_ones(), 0);
+    }
+}
@@ -0,0 +1,267 @@
+//! Coordinate compression, for grids too large and sparse to build directly.
+//!
+//! ```
+//! use advent_rust_lib::{compress::CoordCompressor, grid::Pos2D, grid_ext::Pos2DExt};
+//!
+//! let points = [Pos2DExt::new(2, 10_000), Pos2DExt::new(50_000, 3)];
+//! let compressor = CoordCompressor::with_gaps(points);
+//!
+//! // Each axis has both values and the gap between them
+//! let grid = compressor.grid(false);
+//! assert_eq!((grid.x_max(), grid.y_max()), (3, 3));
+//!
+//! assert_eq!(compressor.compress(points[1]), Some(Pos2D::new(2, 0)));
+//! assert_eq!(compressor.cell_size(Pos2D::new(1, 1)), Some(Pos2DExt::new(49_997, 9_996)));
+//! assert_eq!(compressor.distance(Pos2D::new(0, 0), Pos2D::new(2, 2)), 49_998 + 9_997);
+//! ```
+
+use num::Integer;
+
+use crate::{
+    grid::{Pos2D, RectangleGrid},
+    grid_ext::Pos2DExt,
+};
+
+/// Maps the distinct values along one axis to dense indices.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AxisCompressor<N> {
+    // (first real value, number of real values) for each index, in order
+    cells: Vec<(N, N)>,
+}
+
+impl<N: Integer + Copy> AxisCompressor<N> {
+    /// One index per distinct value.
+    pub fn new<I: IntoIterator<Item = N>>(values: I) -> Self {
+        Self::build(values, false)
+    }
+
+    /// One index per distinct value, plus one between each pair of values
+    /// that are not adjacent, covering all the values skipped over.
+    pub fn with_gaps<I: IntoIterator<Item = N>>(values: I) -> Self {
+        Self::build(values, true)
+    }
+
+    fn build<I: IntoIterator<Item = N>>(values: I, gaps: bool) -> Self {
+        let mut values: Vec<_> = values.into_iter().collect();
+        values.sort_unstable();
+        values.dedup();
+
+        let mut cells = Vec::with_capacity(if gaps { values.len() * 2 } else { values.len() });
+        for (idx, value) in values.iter().enumerate() {
+            cells.push((*value, N::one()));
+            if let Some(next) = values.get(idx + 1).filter(|_| gaps) {
+                let gap = *next - *value - N::one();
+                if gap > N::zero() {
+                    cells.push((*value + N::one(), gap));
+                }
+            }
+        }
+
+        Self { cells }
+    }
+
+    /// Number of indices.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Index covering `value`, if any.
+    pub fn index(&self, value: N) -> Option<usize> {
+        let idx = self
+            .cells
+            .partition_point(|(start, _)| *start <= value)
+            .checked_sub(1)?;
+        let (start, width) = self.cells[idx];
+        (value - start < width).then_some(idx)
+    }
+
+    /// First real value covered by `idx`.
+    pub fn value(&self, idx: usize) -> Option<N> {
+        Some(self.cells.get(idx)?.0)
+    }
+
+    /// Number of real values covered by `idx`.
+    pub fn width(&self, idx: usize) -> Option<N> {
+        Some(self.cells.get(idx)?.1)
+    }
+
+    /// Each index's (first real value, number of real values).
+    pub fn cells(&self) -> impl ExactSizeIterator<Item = (N, N)> + '_ {
+        self.cells.iter().copied()
+    }
+
+    /// Real distance between the first values of two indices.
+    pub fn distance(&self, from: usize, to: usize) -> Option<N> {
+        let (from, to) = (self.value(from)?, self.value(to)?);
+        Some(from.max(to) - from.min(to))
+    }
+}
+
+// ------------------------------------------------------------ //
+
+/// Compresses both axes of a set of 2D points.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CoordCompressor<N> {
+    x: AxisCompressor<N>,
+    y: AxisCompressor<N>,
+}
+
+impl<N: Integer + Copy> CoordCompressor<N> {
+    /// Only the x and y values of `points`, see [`AxisCompressor::new`].
+    pub fn new<I: IntoIterator<Item = Pos2DExt<N>>>(points: I) -> Self {
+        let (x, y): (Vec<_>, Vec<_>) = points.into_iter().map(|pos| (pos.x, pos.y)).unzip();
+        Self {
+            x: AxisCompressor::new(x),
+            y: AxisCompressor::new(y),
+        }
+    }
+
+    /// Keeps cells for the gaps between values, see
+    /// [`AxisCompressor::with_gaps`].
+    pub fn with_gaps<I: IntoIterator<Item = Pos2DExt<N>>>(points: I) -> Self {
+        let (x, y): (Vec<_>, Vec<_>) = points.into_iter().map(|pos| (pos.x, pos.y)).unzip();
+        Self {
+            x: AxisCompressor::with_gaps(x),
+            y: AxisCompressor::with_gaps(y),
+        }
+    }
+
+    pub fn x(&self) -> &AxisCompressor<N> {
+        &self.x
+    }
+
+    pub fn y(&self) -> &AxisCompressor<N> {
+        &self.y
+    }
+
+    /// Compressed cell covering a real position.
+    pub fn compress(&self, pos: Pos2DExt<N>) -> Option<Pos2D> {
+        Some(Pos2D::new(self.x.index(pos.x)?, self.y.index(pos.y)?))
+    }
+
+    /// First real position covered by a compressed cell.
+    pub fn expand(&self, pos: Pos2D) -> Option<Pos2DExt<N>> {
+        Some(Pos2DExt::new(self.x.value(pos.x)?, self.y.value(pos.y)?))
+    }
+
+    /// Real width and height of a compressed cell.
+    pub fn cell_size(&self, pos: Pos2D) -> Option<Pos2DExt<N>> {
+        Some(Pos2DExt::new(self.x.width(pos.x)?, self.y.width(pos.y)?))
+    }
+
+    /// Grid with a cell for each compressed position.
+    pub fn grid<T: Clone>(&self, default: T) -> RectangleGrid<T> {
+        RectangleGrid::default_with_dim(self.x.len(), self.y.len(), default)
+    }
+
+    /// Grid built from each cell's first real position and real size.
+    pub fn grid_with<T, F>(&self, mut to_cell: F) -> RectangleGrid<T>
+    where
+        F: FnMut(Pos2DExt<N>, Pos2DExt<N>) -> T,
+    {
+        self.grid(()).map(|entry| {
+            let pos = entry.position();
+            let (start, size) = self
+                .expand(pos)
+                .zip(self.cell_size(pos))
+                .expect("Cell is in the compressed grid");
+            to_cell(start, size)
+        })
+    }
+
+    /// Real area covered by compressed cells.
+    ///
+    /// # Panics
+    /// If any position is outside the compressed grid.
+    pub fn area<I: IntoIterator<Item = Pos2D>>(&self, cells: I) -> N {
+        cells
+            .into_iter()
+            .map(|pos| {
+                let size = self.cell_size(pos).expect("Cell is in the compressed grid");
+                size.x * size.y
+            })
+            .fold(N::zero(), |acc, area| acc + area)
+    }
+
+    /// Real Manhattan distance between the first positions of two cells.
+    ///
+    /// # Panics
+    /// If either position is outside the compressed grid.
+    pub fn distance(&self, from: Pos2D, to: Pos2D) -> N {
+        let x = self.x.distance(from.x, to.x);
+        let y = self.y.distance(from.y, to.y);
+        x.zip(y)
+            .map(|(x, y)| x + y)
+            .expect("Cells are in the compressed grid")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn axis() {
+        let plain = AxisCompressor::new([40, 10, 11, 40, -5]);
+        assert_eq!(plain.len(), 4);
+        assert_eq!(plain.index(11), Some(2));
+        assert_eq!(plain.index(12), None);
+        assert_eq!(plain.index(-6), None);
+        assert_eq!(plain.distance(0, 3), Some(45));
+
+        let gaps = AxisCompressor::with_gaps([40, 10, 11, 40, -5]);
+        let cells: Vec<_> = gaps.cells().collect();
+        assert_eq!(
+            cells,
+            [(-5, 1), (-4, 14), (10, 1), (11, 1), (12, 28), (40, 1)]
+        );
+        assert_eq!(gaps.index(0), Some(1));
+        assert_eq!(gaps.index(41), None);
+        assert_eq!(gaps.width(4), Some(28));
+
+        assert!(AxisCompressor::<i64>::new([]).is_empty());
+        let empty = CoordCompressor::<i64>::with_gaps([]);
+        assert_eq!(empty.grid_with(|start, _| start).items().count(), 0);
+    }
+
+    #[test]
+    fn coords() {
+        let points = [(1, 5), (4, 5), (4, 6)].map(|(x, y)| Pos2DExt::new(x, y));
+
+        // Columns for 1, 2..=3 and 4, and rows for 5 and 6
+        let compressor = CoordCompressor::with_gaps(points);
+        assert_eq!(
+            compressor.compress(Pos2DExt::new(3, 6)),
+            Some(Pos2D::new(1, 1))
+        );
+        assert_eq!(compressor.compress(Pos2DExt::new(5, 5)), None);
+        assert_eq!(
+            compressor.expand(Pos2D::new(1, 1)),
+            Some(Pos2DExt::new(2, 6))
+        );
+        assert_eq!(compressor.expand(Pos2D::new(3, 0)), None);
+        assert_eq!(
+            compressor.cell_size(Pos2D::new(1, 0)),
+            Some(Pos2DExt::new(2, 1))
+        );
+        assert_eq!(
+            compressor.area([Pos2D::new(0, 0), Pos2D::new(1, 0), Pos2D::new(1, 1)]),
+            1 + 2 + 2
+        );
+
+        let sizes = compressor.grid_with(|_, size| size.x * size.y);
+        assert_eq!((sizes.x_max(), sizes.y_max()), (3, 2));
+        assert_eq!(sizes.items().sum::<i64>(), 4 * 2);
+        let starts = compressor.grid_with(|start, _| start);
+        assert_eq!(starts.get(Pos2D::new(2, 1)), Some(&Pos2DExt::new(4, 6)));
+
+        let plain = CoordCompressor::new(points);
+        assert_eq!(plain.grid(0).x_max(), 2);
+        assert_eq!(plain.compress(Pos2DExt::new(2, 5)), None);
+        assert_eq!(plain.distance(Pos2D::new(0, 0), Pos2D::new(1, 1)), 3 + 1);
+    }
+}
@@ -1,8 +1,12 @@
 use std::{
     cmp::Ordering,
+    collections::{HashMap, HashSet},
     fmt::Display,
+    hash::Hash,
     io::Write,
+    iter::{StepBy, Take},
     ops::{Add, Sub},
+    slice,
 };
 
 use thiserror::Error;
@@ -302,6 +306,185 @@ where
 
 // -------------------------------------------------- //
 
+/// Position type of a [`Grid`].
+pub trait GridPos: Copy + Eq + Hash {
+    /// Positions up, down, left and right, skipping any that overflow.
+    fn orthogonal(self) -> impl Iterator<Item = Self>;
+
+    /// Orthogonal and diagonal positions, skipping any that overflow.
+    fn surrounding(self) -> impl Iterator<Item = Self>;
+}
+
+impl GridPos for Pos2D {
+    fn orthogonal(self) -> impl Iterator<Item = Self> {
+        [self.up(), self.right(), self.down(), self.left()]
+            .into_iter()
+            .flatten()
+    }
+
+    fn surrounding(self) -> impl Iterator<Item = Self> {
+        self.surrounding_pos()
+    }
+}
+
+/// Read access shared by the grid types.
+pub trait Grid {
+    type Cell;
+    type Pos: GridPos;
+
+    /// Returns true if `pos` can hold a cell in this grid.
+    fn in_grid(&self, pos: Self::Pos) -> bool;
+
+    fn get(&self, pos: Self::Pos) -> Option<&Self::Cell>;
+
+    /// Every position holding a cell.
+    fn positions(&self) -> impl Iterator<Item = Self::Pos> + '_;
+
+    /// Every cell with its position.
+    fn cells(&self) -> impl Iterator<Item = (Self::Pos, &Self::Cell)> + '_ {
+        self.positions()
+            .filter_map(|pos| Some((pos, self.get(pos)?)))
+    }
+
+    /// Positions up, down, left and right of `pos` that are in this grid.
+    fn neighbors4(&self, pos: Self::Pos) -> impl Iterator<Item = Self::Pos> + '_ {
+        pos.orthogonal().filter(|next| self.in_grid(*next))
+    }
+
+    /// Orthogonal and diagonal positions around `pos` that are in this grid.
+    fn neighbors8(&self, pos: Self::Pos) -> impl Iterator<Item = Self::Pos> + '_ {
+        pos.surrounding().filter(|next| self.in_grid(*next))
+    }
+
+    /// Every position orthogonally connected to `start` through cells that
+    /// `include` accepts.
+    ///
+    /// Empty if `start` itself is not in this grid or not accepted.
+    fn flood_fill<F>(&self, start: Self::Pos, mut include: F) -> HashSet<Self::Pos>
+    where
+        F: FnMut(Self::Pos, &Self::Cell) -> bool,
+    {
+        let mut filled = HashSet::new();
+        let mut accept =
+            |pos| self.in_grid(pos) && self.get(pos).is_some_and(|cell| include(pos, cell));
+
+        if !accept(start) {
+            return filled;
+        }
+        filled.insert(start);
+
+        let mut stack = vec![start];
+        while let Some(pos) = stack.pop() {
+            for next in self.neighbors4(pos) {
+                if !filled.contains(&next) && accept(next) {
+                    filled.insert(next);
+                    stack.push(next);
+                }
+            }
+        }
+
+        filled
+    }
+
+    /// Splits every cell into regions, joining orthogonal neighbors when
+    /// `connected` is true for their cells.
+    ///
+    /// ```
+    /// use advent_rust_lib::grid::{Grid, Pos2D, RectangleGrid};
+    ///
+    /// let garden = RectangleGrid::try_from_iter(["AAB".chars(), "BAB".chars()]).unwrap();
+    /// let plots = garden.label_regions(|lhs, rhs| lhs == rhs);
+    ///
+    /// assert_eq!(plots.len(), 3);
+    /// assert_eq!(plots.label(Pos2D::new(1, 1)), plots.label(Pos2D::new(0, 0)));
+    /// assert_ne!(plots.label(Pos2D::new(0, 1)), plots.label(Pos2D::new(2, 1)));
+    /// ```
+    fn label_regions<F>(&self, mut connected: F) -> Regions<Self::Pos>
+    where
+        F: FnMut(&Self::Cell, &Self::Cell) -> bool,
+    {
+        let mut labels = HashMap::new();
+        let mut regions = Vec::new();
+
+        for (start, _) in self.cells() {
+            if labels.contains_key(&start) {
+                continue;
+            }
+
+            let label = regions.len();
+            labels.insert(start, label);
+            let mut region = vec![start];
+
+            let mut stack = vec![start];
+            while let Some(pos) = stack.pop() {
+                let Some(cell) = self.get(pos) else {
+                    continue;
+                };
+
+                for next in self.neighbors4(pos) {
+                    if labels.contains_key(&next) {
+                        continue;
+                    }
+                    if self
+                        .get(next)
+                        .is_some_and(|next_cell| connected(cell, next_cell))
+                    {
+                        labels.insert(next, label);
+                        region.push(next);
+                        stack.push(next);
+                    }
+                }
+            }
+
+            regions.push(region);
+        }
+
+        Regions { labels, regions }
+    }
+}
+
+/// Write access for grids with a fixed set of positions.
+pub trait GridMut: Grid {
+    /// Replaces the cell at `pos`, returning the previous cell, or `None` if
+    /// `pos` is not in the grid.
+    fn replace(&mut self, pos: Self::Pos, cell: Self::Cell) -> Option<Self::Cell>;
+}
+
+/// Connected regions of a grid, from [`Grid::label_regions`].
+#[derive(Debug, Clone)]
+pub struct Regions<P> {
+    labels: HashMap<P, usize>,
+    regions: Vec<Vec<P>>,
+}
+
+impl<P: Eq + Hash> Regions<P> {
+    /// Number of regions.
+    pub fn len(&self) -> usize {
+        self.regions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+
+    /// Index of the region containing `pos`.
+    pub fn label(&self, pos: P) -> Option<usize> {
+        self.labels.get(&pos).copied()
+    }
+
+    /// Positions in the region with index `label`.
+    pub fn region(&self, label: usize) -> Option<&[P]> {
+        self.regions.get(label).map(Vec::as_slice)
+    }
+
+    /// Positions in every region, in order of their labels.
+    pub fn iter(&self) -> impl ArrayIter<&[P]> {
+        self.regions.iter().map(Vec::as_slice)
+    }
+}
+
+// -------------------------------------------------- //
+
 #[derive(Debug, Clone)]
 /// Rectangle grid with a flat inner representation.
 pub struct RectangleGrid<T> {
@@ -311,8 +494,81 @@ pub struct RectangleGrid<T> {
 }
 
 #[derive(Debug, Error)]
-#[error("At least one line of the iterator was a different length.")]
-pub struct NonRectangleInput;
+#[error("{}", match line {
+    Some(line) => format!("Line {line} of the input was a different length."),
+    None => "At least one line of the iterator was a different length.".to_string(),
+})]
+pub struct NonRectangleInput {
+    /// 1-indexed line that did not match the first line, when known.
+    pub line: Option<usize>,
+}
+
+/// A character with no matching [`GridCell`].
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+#[error("No grid cell is written as {0:?}")]
+pub struct UnknownCell(pub char);
+
+#[derive(Debug, Error)]
+pub enum ParseCellError {
+    #[error("{0}")]
+    NonRectangle(#[from] NonRectangleInput),
+    #[error("No grid cell is written as {cell:?}, found at {pos}")]
+    UnknownCell { cell: char, pos: Pos2D },
+}
+
+pub use advent_rust_lib_derive::GridCell;
+
+/// A cell written as a single character, for [`RectangleGrid::parse_cells`]
+/// and printing grids.
+///
+/// Usually derived for enums, with the characters for each variant:
+///
+/// ```
+/// use advent_rust_lib::grid::{GridCell, Pos2D, RectangleGrid};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq, GridCell)]
+/// enum Tile {
+///     // Printed as '.', but the start and end are also open
+///     #[cell('.', 'S', 'E')]
+///     Open,
+///     #[cell('#')]
+///     Wall,
+/// }
+///
+/// assert_eq!(Tile::try_from('S'), Ok(Tile::Open));
+/// assert!(Tile::try_from('?').is_err());
+///
+/// let (grid, markers) = RectangleGrid::<Tile>::parse_cells("#S.\n.#E", &['S', 'E']).unwrap();
+/// assert_eq!(grid.at(Pos2D::new(1, 1)), Some(Tile::Wall));
+/// assert_eq!(markers.first('E'), Some(Pos2D::new(2, 1)));
+/// assert_eq!(grid.to_string(), "#..\n.#.\n");
+/// ```
+pub trait GridCell: Sized {
+    fn from_char(c: char) -> Option<Self>;
+
+    fn to_char(&self) -> char;
+}
+
+/// Positions of marker characters found by [`RectangleGrid::parse_chars`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Markers {
+    inner: HashMap<char, Vec<Pos2D>>,
+}
+
+impl Markers {
+    /// First occurrence of `marker`, in reading order.
+    pub fn first(&self, marker: char) -> Option<Pos2D> {
+        self.all(marker).first().copied()
+    }
+
+    /// Every occurrence of `marker`, in reading order.
+    pub fn all(&self, marker: char) -> &[Pos2D] {
+        self.inner
+            .get(&marker)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+}
 
 impl<T> RectangleGrid<T> {
     /// Attempt to construct this from a 2D iterator.
@@ -336,7 +592,7 @@ impl<T> RectangleGrid<T> {
             inner.extend(iter.flat_map(|x| x.into_iter()));
 
             if (inner.len() % x_max) != 0 {
-                return Err(NonRectangleInput);
+                return Err(NonRectangleInput { line: None });
             }
 
             y_max = inner.len() / x_max;
@@ -367,10 +623,12 @@ impl<T> RectangleGrid<T> {
             inner = first.into_iter().collect();
             x_max = inner.len();
 
-            for x in iter {
+            for (idx, x) in iter.enumerate() {
                 let mut x: Vec<_> = x.into_iter().collect();
                 if x.len() != x_max {
-                    return Err(NonRectangleInput);
+                    return Err(NonRectangleInput {
+                        line: Some(idx + 2),
+                    });
                 }
                 inner.append(&mut x);
             }
@@ -385,6 +643,87 @@ impl<T> RectangleGrid<T> {
         })
     }
 
+    /// Parse a grid of characters, one line per row.
+    ///
+    /// Every character is converted with `to_cell`, including markers. The
+    /// positions of any character in `markers` are also recorded.
+    ///
+    /// ```
+    /// use advent_rust_lib::grid::{Pos2D, RectangleGrid};
+    ///
+    /// let (walls, markers) = RectangleGrid::parse_chars("#S.\n.#E", &['S', 'E'], |c| c == '#').unwrap();
+    ///
+    /// assert_eq!(walls.at(Pos2D::new(1, 1)), Some(true));
+    /// assert_eq!(markers.first('E'), Some(Pos2D::new(2, 1)));
+    /// assert_eq!(
+    ///     RectangleGrid::parse_chars("##\n#", &[], |c| c).unwrap_err().line,
+    ///     Some(2)
+    /// );
+    /// ```
+    pub fn parse_chars<F>(
+        input: &str,
+        markers: &[char],
+        mut to_cell: F,
+    ) -> Result<(Self, Markers), NonRectangleInput>
+    where
+        F: FnMut(char) -> T,
+    {
+        Self::try_parse_chars(input, markers, |c, _| Ok(to_cell(c)))
+    }
+
+    /// Same as [`Self::parse_chars`], with each character converted by
+    /// [`GridCell::from_char`].
+    pub fn parse_cells(input: &str, markers: &[char]) -> Result<(Self, Markers), ParseCellError>
+    where
+        T: GridCell,
+    {
+        Self::try_parse_chars(input, markers, |cell, pos| {
+            T::from_char(cell).ok_or(ParseCellError::UnknownCell { cell, pos })
+        })
+    }
+
+    fn try_parse_chars<F, E>(
+        input: &str,
+        markers: &[char],
+        mut to_cell: F,
+    ) -> Result<(Self, Markers), E>
+    where
+        F: FnMut(char, Pos2D) -> Result<T, E>,
+        E: From<NonRectangleInput>,
+    {
+        let mut found = Markers::default();
+        let mut inner = Vec::new();
+        let mut x_max = 0;
+        let mut y_max = 0;
+
+        for (y, line) in input.lines().enumerate() {
+            let line_start = inner.len();
+
+            for (x, c) in line.chars().enumerate() {
+                let pos = Pos2D::new(x, y);
+                if markers.contains(&c) {
+                    found.inner.entry(c).or_default().push(pos);
+                }
+                inner.push(to_cell(c, pos)?);
+            }
+
+            let width = inner.len() - line_start;
+            if y == 0 {
+                x_max = width;
+            } else if width != x_max {
+                return Err(NonRectangleInput { line: Some(y + 1) }.into());
+            }
+            y_max = y + 1;
+        }
+
+        let grid = Self {
+            inner: inner.into_boxed_slice(),
+            x_max,
+            y_max,
+        };
+        Ok((grid, found))
+    }
+
     /// Attempt to construct this from a 2D iterator.
     ///
     /// May fail when the iterator is non-square, but is not guaranteed to.
@@ -445,12 +784,89 @@ impl<T> RectangleGrid<T> {
             .then(|| &mut self.inner[self.flat_pos(pos)])
     }
 
+    // Zero width grids are empty, and have no lines to chunk
     pub fn lines(&self) -> impl ArrayIter<&[T]> {
-        self.inner.chunks(self.x_max)
+        self.inner.chunks(self.x_max.max(1))
     }
 
     pub fn lines_mut(&mut self) -> impl ArrayIter<&mut [T]> {
-        self.inner.chunks_mut(self.x_max)
+        self.inner.chunks_mut(self.x_max.max(1))
+    }
+
+    /// Each column, from left to right, read top to bottom.
+    pub fn columns(&self) -> impl ArrayIter<Column<'_, T>> {
+        // With no lines, `x` can be past the end of `inner`
+        (0..self.x_max)
+            .map(|x| Column::new(self.inner.get(x..).unwrap_or(&[]), self.x_max, self.y_max))
+    }
+
+    /// Column `x`, read top to bottom.
+    pub fn column(&self, x: usize) -> Option<Column<'_, T>> {
+        (x < self.x_max).then(|| Column::new(&self.inner[x..], self.x_max, self.y_max))
+    }
+
+    /// Column `x`, read top to bottom.
+    pub fn column_mut(&mut self, x: usize) -> Option<ColumnMut<'_, T>> {
+        (x < self.x_max).then(|| ColumnMut::new(&mut self.inner[x..], self.x_max, self.y_max))
+    }
+
+    /// Number of diagonals in either direction.
+    fn diagonal_count(&self) -> usize {
+        if self.x_max == 0 || self.y_max == 0 {
+            0
+        } else {
+            self.x_max + self.y_max - 1
+        }
+    }
+
+    /// Each diagonal running down and right, starting from the bottom left
+    /// corner and ending at the top right corner.
+    ///
+    /// ```
+    /// use advent_rust_lib::grid::RectangleGrid;
+    ///
+    /// let grid = RectangleGrid::try_from_iter(["abc".chars(), "def".chars()]).unwrap();
+    ///
+    /// let diagonals: Vec<String> = grid.diagonals().map(|diagonal| diagonal.collect()).collect();
+    /// assert_eq!(diagonals, ["d", "ae", "bf", "c"]);
+    /// ```
+    pub fn diagonals(&self) -> impl ArrayIter<Column<'_, T>> {
+        (0..self.diagonal_count()).map(|idx| {
+            let start = if idx < self.y_max {
+                Pos2D::new(0, self.y_max - 1 - idx)
+            } else {
+                Pos2D::new(idx + 1 - self.y_max, 0)
+            };
+            let len = (self.x_max - start.x).min(self.y_max - start.y);
+
+            Column::new(&self.inner[self.flat_pos(start)..], self.x_max + 1, len)
+        })
+    }
+
+    /// Each diagonal running down and left, starting from the top left corner
+    /// and ending at the bottom right corner.
+    ///
+    /// ```
+    /// use advent_rust_lib::grid::RectangleGrid;
+    ///
+    /// let grid = RectangleGrid::try_from_iter(["abc".chars(), "def".chars()]).unwrap();
+    ///
+    /// let diagonals: Vec<String> = grid.anti_diagonals().map(|diagonal| diagonal.collect()).collect();
+    /// assert_eq!(diagonals, ["a", "bd", "ce", "f"]);
+    /// ```
+    pub fn anti_diagonals(&self) -> impl ArrayIter<Column<'_, T>> {
+        (0..self.diagonal_count()).map(|idx| {
+            let start = if idx < self.x_max {
+                Pos2D::new(idx, 0)
+            } else {
+                Pos2D::new(self.x_max - 1, idx + 1 - self.x_max)
+            };
+            let len = (start.x + 1).min(self.y_max - start.y);
+
+            // A single column has one item per diagonal, so the stride is unused
+            let stride = (self.x_max - 1).max(1);
+            Column::new(&self.inner[self.flat_pos(start)..], stride, len)
+        })
     }
 
     pub fn items(&self) -> impl ArrayIter<&T> {
@@ -472,9 +888,32 @@ impl<T> RectangleGrid<T> {
     }
 }
 
+impl<T> Grid for RectangleGrid<T> {
+    type Cell = T;
+    type Pos = Pos2D;
+
+    fn in_grid(&self, pos: Pos2D) -> bool {
+        RectangleGrid::in_grid(self, pos)
+    }
+
+    fn get(&self, pos: Pos2D) -> Option<&T> {
+        RectangleGrid::get(self, pos)
+    }
+
+    fn positions(&self) -> impl Iterator<Item = Pos2D> + '_ {
+        RectangleGrid::positions(self)
+    }
+}
+
+impl<T> GridMut for RectangleGrid<T> {
+    fn replace(&mut self, pos: Pos2D, cell: T) -> Option<T> {
+        self.get_mut(pos).map(|old| std::mem::replace(old, cell))
+    }
+}
+
 impl<T: Copy> RectangleGrid<T> {
     pub fn at(&self, pos: Pos2D) -> Option<T> {
-        self.in_grid(pos).then_some(self.inner[self.flat_pos(pos)])
+        self.in_grid(pos).then(|| self.inner[self.flat_pos(pos)])
     }
 }
 
@@ -533,27 +972,377 @@ impl<T> RectangleGrid<T> {
     }
 }
 
+/// Cells to add on each side of a grid, for [`RectangleGrid::pad_sides`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Padding {
+    pub top: usize,
+    pub right: usize,
+    pub bottom: usize,
+    pub left: usize,
+}
+
+impl Padding {
+    /// `n` cells on every side.
+    pub const fn uniform(n: usize) -> Self {
+        Self {
+            top: n,
+            right: n,
+            bottom: n,
+            left: n,
+        }
+    }
+}
+
 impl<T: Clone> RectangleGrid<T> {
-    // Evenly the outside with a given value
+    /// Surrounds the grid with a border of `value`, one cell wide.
     pub fn pad_surrounding(&self, value: T) -> Self {
-        let horizontal_pad = std::iter::repeat_n(value.clone(), self.x_max + 1);
-        let vertical_pad = self.lines().flat_map(|line| {
-            std::iter::once(value.clone())
-                .chain(line.iter().cloned())
-                .chain(std::iter::once(value.clone()))
+        self.pad(1, value)
+    }
+
+    /// Surrounds the grid with a border of `value`, `n` cells wide.
+    pub fn pad(&self, n: usize, value: T) -> Self {
+        self.pad_sides(Padding::uniform(n), value)
+    }
+
+    /// Adds `padding` cells of `value` to each side.
+    ///
+    /// ```
+    /// use advent_rust_lib::grid::{Padding, RectangleGrid};
+    ///
+    /// let grid = RectangleGrid::try_from_iter(["ab".chars()]).unwrap();
+    /// let padded = grid.pad_sides(Padding { left: 2, bottom: 1, ..Padding::default() }, '.');
+    ///
+    /// let lines: Vec<String> = padded.lines().map(|line| line.iter().collect()).collect();
+    /// assert_eq!(lines, ["..ab", "...."]);
+    /// ```
+    pub fn pad_sides(&self, padding: Padding, value: T) -> Self {
+        let x_max = padding.left + self.x_max + padding.right;
+        let y_max = padding.top + self.y_max + padding.bottom;
+
+        let inner = (0..y_max)
+            .flat_map(|y| (0..x_max).map(move |x| (x, y)))
+            .map(|(x, y)| {
+                let original = x
+                    .checked_sub(padding.left)
+                    .zip(y.checked_sub(padding.top))
+                    .and_then(|(x, y)| self.get(Pos2D::new(x, y)));
+                original.unwrap_or(&value).clone()
+            })
+            .collect();
+
+        Self {
+            inner,
+            x_max,
+            y_max,
+        }
+    }
+
+    /// Copies the `dims` sized rectangle starting at `top_left`.
+    ///
+    /// Returns `None` if the rectangle does not fit in this grid.
+    pub fn crop(&self, top_left: Pos2D, dims: Pos2D) -> Option<Self> {
+        Some(self.window(top_left, dims)?.to_grid())
+    }
+
+    /// Removes outer lines and columns where `predicate` holds for every cell,
+    /// shrinking to the bounding box of the remaining cells.
+    ///
+    /// Returns an empty grid if `predicate` holds everywhere.
+    ///
+    /// ```
+    /// use advent_rust_lib::grid::RectangleGrid;
+    ///
+    /// let grid = RectangleGrid::try_from_iter(["....".chars(), ".#..".chars(), "..#.".chars()]).unwrap();
+    /// let trimmed = grid.trim_where(|c| *c == '.');
+    ///
+    /// let lines: Vec<String> = trimmed.lines().map(|line| line.iter().collect()).collect();
+    /// assert_eq!(lines, ["#.", ".#"]);
+    /// ```
+    pub fn trim_where<F>(&self, mut predicate: F) -> Self
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let kept = self
+            .positioned_items()
+            .filter(|entry| !predicate(entry.value))
+            .map(|entry| entry.position());
+
+        let bounds = kept.fold(None, |bounds: Option<(Pos2D, Pos2D)>, pos| {
+            Some(match bounds {
+                None => (pos, pos),
+                Some((low, high)) => (
+                    Pos2D::new(low.x.min(pos.x), low.y.min(pos.y)),
+                    Pos2D::new(high.x.max(pos.x), high.y.max(pos.y)),
+                ),
+            })
         });
 
-        let new_arr = horizontal_pad
-            .clone()
-            .chain(vertical_pad)
-            .chain(horizontal_pad)
+        match bounds {
+            Some((low, high)) => self
+                .crop(low, Pos2D::new(high.x - low.x + 1, high.y - low.y + 1))
+                .expect("bounds are within the grid"),
+            None => Self {
+                inner: Box::new([]),
+                x_max: 0,
+                y_max: 0,
+            },
+        }
+    }
+}
+
+impl<T: Clone> RectangleGrid<T> {
+    /// Builds an `x_max` by `y_max` grid, copying each position from `source`
+    /// in this grid.
+    fn remapped<F>(&self, x_max: usize, y_max: usize, source: F) -> Self
+    where
+        F: Fn(Pos2D) -> Pos2D,
+    {
+        let inner = (0..y_max)
+            .flat_map(|y| (0..x_max).map(move |x| Pos2D::new(x, y)))
+            .map(|pos| self.inner[self.flat_pos(source(pos))].clone())
             .collect();
+
         Self {
-            inner: new_arr,
-            x_max: self.x_max + 1,
-            y_max: self.y_max + 1,
+            inner,
+            x_max,
+            y_max,
         }
     }
+
+    /// Swaps rows and columns.
+    ///
+    /// ```
+    /// use advent_rust_lib::grid::RectangleGrid;
+    ///
+    /// let grid = RectangleGrid::try_from_iter(["abc".chars(), "def".chars()]).unwrap();
+    /// let transposed = grid.transpose();
+    ///
+    /// let lines: Vec<String> = transposed.lines().map(|line| line.iter().collect()).collect();
+    /// assert_eq!(lines, ["ad", "be", "cf"]);
+    /// ```
+    pub fn transpose(&self) -> Self {
+        self.remapped(self.y_max, self.x_max, |pos| Pos2D::new(pos.y, pos.x))
+    }
+
+    /// Rotates a quarter turn clockwise, so the first line becomes the last
+    /// column.
+    pub fn rotate_cw(&self) -> Self {
+        self.remapped(self.y_max, self.x_max, |pos| {
+            Pos2D::new(pos.y, self.y_max - 1 - pos.x)
+        })
+    }
+
+    /// Rotates a quarter turn counterclockwise, so the first line becomes the
+    /// first column.
+    pub fn rotate_ccw(&self) -> Self {
+        self.remapped(self.y_max, self.x_max, |pos| {
+            Pos2D::new(self.x_max - 1 - pos.y, pos.x)
+        })
+    }
+
+    /// Mirrors left to right.
+    pub fn flip_horizontal(&self) -> Self {
+        self.remapped(self.x_max, self.y_max, |pos| {
+            Pos2D::new(self.x_max - 1 - pos.x, pos.y)
+        })
+    }
+
+    /// Mirrors top to bottom.
+    pub fn flip_vertical(&self) -> Self {
+        self.remapped(self.x_max, self.y_max, |pos| {
+            Pos2D::new(pos.x, self.y_max - 1 - pos.y)
+        })
+    }
+}
+
+impl<T> RectangleGrid<T> {
+    /// Borrowed view of the `dims` sized rectangle starting at `top_left`.
+    ///
+    /// Returns `None` if the rectangle does not fit in this grid.
+    ///
+    /// ```
+    /// use advent_rust_lib::grid::{Pos2D, RectangleGrid};
+    ///
+    /// let grid = RectangleGrid::try_from_iter(["abcd".chars(), "efgh".chars(), "ijkl".chars()]).unwrap();
+    /// let window = grid.window(Pos2D::new(1, 1), Pos2D::new(2, 2)).unwrap();
+    ///
+    /// assert_eq!(window.at(Pos2D::new(1, 0)), Some('g'));
+    /// assert_eq!(window.lines().collect::<Vec<_>>(), [&['f', 'g'], &['j', 'k']]);
+    /// assert!(grid.window(Pos2D::new(3, 0), Pos2D::new(2, 1)).is_none());
+    /// ```
+    pub fn window(&self, top_left: Pos2D, dims: Pos2D) -> Option<GridWindow<'_, T>> {
+        let fits = top_left.x + dims.x <= self.x_max && top_left.y + dims.y <= self.y_max;
+
+        fits.then_some(GridWindow {
+            grid: self,
+            top_left,
+            x_max: dims.x,
+            y_max: dims.y,
+        })
+    }
+}
+
+/// Items in one column, or diagonal, of a grid, from top to bottom.
+#[derive(Debug, Clone)]
+pub struct Column<'a, T> {
+    inner: Take<StepBy<slice::Iter<'a, T>>>,
+}
+
+impl<'a, T> Column<'a, T> {
+    /// `len` items from `items`, which starts at the top of the column.
+    fn new(items: &'a [T], stride: usize, len: usize) -> Self {
+        Self {
+            inner: items.iter().step_by(stride).take(len),
+        }
+    }
+}
+
+impl<'a, T> Iterator for Column<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.inner.nth(n)
+    }
+}
+
+impl<T> DoubleEndedIterator for Column<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for Column<'_, T> {}
+
+/// Mutable items in one column of a grid, from top to bottom.
+#[derive(Debug)]
+pub struct ColumnMut<'a, T> {
+    inner: Take<StepBy<slice::IterMut<'a, T>>>,
+}
+
+impl<'a, T> ColumnMut<'a, T> {
+    /// `len` items from `items`, which starts at the top of the column.
+    fn new(items: &'a mut [T], stride: usize, len: usize) -> Self {
+        Self {
+            inner: items.iter_mut().step_by(stride).take(len),
+        }
+    }
+}
+
+impl<'a, T> Iterator for ColumnMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.inner.nth(n)
+    }
+}
+
+impl<T> DoubleEndedIterator for ColumnMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for ColumnMut<'_, T> {}
+
+/// Rectangular part of a [`RectangleGrid`], indexed from its own top left.
+#[derive(Debug, Clone, Copy)]
+pub struct GridWindow<'a, T> {
+    grid: &'a RectangleGrid<T>,
+    top_left: Pos2D,
+    x_max: usize,
+    y_max: usize,
+}
+
+impl<'a, T> GridWindow<'a, T> {
+    pub fn x_max(&self) -> usize {
+        self.x_max
+    }
+
+    pub fn y_max(&self) -> usize {
+        self.y_max
+    }
+
+    /// Position of this window's top left in the full grid.
+    pub fn top_left(&self) -> Pos2D {
+        self.top_left
+    }
+
+    pub fn in_grid(&self, pos: Pos2D) -> bool {
+        (pos.y < self.y_max) && (pos.x < self.x_max)
+    }
+
+    pub fn get(&self, pos: Pos2D) -> Option<&'a T> {
+        self.in_grid(pos)
+            .then(|| &self.grid.inner[self.grid.flat_pos(self.top_left + pos)])
+    }
+
+    pub fn lines(&self) -> impl ArrayIter<&'a [T]> + use<'a, T> {
+        let Self {
+            grid,
+            top_left,
+            x_max,
+            y_max,
+        } = *self;
+
+        (top_left.y..top_left.y + y_max).map(move |y| {
+            let start = grid.flat_pos(Pos2D::new(top_left.x, y));
+            &grid.inner[start..start + x_max]
+        })
+    }
+
+    /// Each column, from left to right, read top to bottom.
+    pub fn columns(&self) -> impl ArrayIter<Column<'a, T>> + use<'a, T> {
+        let Self {
+            grid,
+            top_left,
+            x_max,
+            y_max,
+        } = *self;
+
+        (top_left.x..top_left.x + x_max).map(move |x| {
+            // A window with no lines can start past the end of the grid
+            let start = grid.flat_pos(Pos2D::new(x, top_left.y));
+            Column::new(grid.inner.get(start..).unwrap_or(&[]), grid.x_max, y_max)
+        })
+    }
+
+    pub fn items(&self) -> impl Iterator<Item = &'a T> + use<'a, T> {
+        self.lines().flatten()
+    }
+
+    /// Copies this window into its own grid.
+    pub fn to_grid(&self) -> RectangleGrid<T>
+    where
+        T: Clone,
+    {
+        RectangleGrid {
+            inner: self.items().cloned().collect(),
+            x_max: self.x_max,
+            y_max: self.y_max,
+        }
+    }
+}
+
+impl<T: Copy> GridWindow<'_, T> {
+    pub fn at(&self, pos: Pos2D) -> Option<T> {
+        self.get(pos).copied()
+    }
 }
 
 impl<T> RectangleGrid<T> {
@@ -573,3 +1362,208 @@ impl<T> RectangleGrid<T> {
         Ok(())
     }
 }
+
+/// Writes each cell's character, one line per row.
+impl<T: GridCell> Display for RectangleGrid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for line in self.lines() {
+            for item in line {
+                write!(f, "{}", item.to_char())?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(lines: &[&str]) -> RectangleGrid<char> {
+        RectangleGrid::try_from_iter_strict(lines.iter().map(|line| line.chars())).unwrap()
+    }
+
+    fn text(grid: &RectangleGrid<char>) -> Vec<String> {
+        grid.lines().map(|line| line.iter().collect()).collect()
+    }
+
+    #[test]
+    fn transforms() {
+        let abc = grid(&["abc", "def"]);
+
+        assert_eq!(text(&abc.rotate_cw()), ["da", "eb", "fc"]);
+        assert_eq!(text(&abc.rotate_ccw()), ["cf", "be", "ad"]);
+        assert_eq!(text(&abc.flip_horizontal()), ["cba", "fed"]);
+        assert_eq!(text(&abc.flip_vertical()), ["def", "abc"]);
+        assert_eq!(text(&abc.transpose().transpose()), text(&abc));
+        assert_eq!(
+            text(&abc.rotate_cw().rotate_cw()),
+            text(&abc.flip_horizontal().flip_vertical())
+        );
+
+        let empty = grid(&[]);
+        assert_eq!(empty.rotate_cw().x_max(), 0);
+    }
+
+    #[test]
+    fn columns_and_windows() {
+        let abc = grid(&["abcd", "efgh", "ijkl"]);
+
+        let columns: Vec<String> = abc.columns().map(|column| column.collect()).collect();
+        assert_eq!(columns, ["aei", "bfj", "cgk", "dhl"]);
+        assert_eq!(abc.columns().next_back().unwrap().rev().len(), 3);
+
+        let window = abc.window(Pos2D::new(2, 1), Pos2D::new(2, 2)).unwrap();
+        let columns: Vec<String> = window.columns().map(|column| column.collect()).collect();
+        assert_eq!(columns, ["gk", "hl"]);
+        assert_eq!(window.get(Pos2D::new(2, 0)), None);
+        assert_eq!(text(&window.to_grid()), ["gh", "kl"]);
+
+        let flat = RectangleGrid::default_with_dim(3, 2, 0);
+        let bottom = flat.window(Pos2D::new(1, 2), Pos2D::new(2, 0)).unwrap();
+        assert_eq!(bottom.columns().len(), 2);
+        assert!(bottom.columns().all(|mut column| column.next().is_none()));
+        let no_lines = RectangleGrid::default_with_dim(3, 0, 0);
+        assert!(no_lines.columns().all(|column| column.len() == 0));
+    }
+
+    #[test]
+    fn column_access() {
+        let mut abc = grid(&["abc", "def"]);
+
+        assert_eq!(abc.column(1).unwrap().collect::<String>(), "be");
+        assert!(abc.column(3).is_none());
+
+        abc.column_mut(2)
+            .unwrap()
+            .for_each(|c| *c = c.to_ascii_uppercase());
+        assert_eq!(text(&abc), ["abC", "deF"]);
+    }
+
+    #[test]
+    fn diagonals() {
+        let collect = |diagonals: Vec<Column<'_, char>>| -> Vec<String> {
+            diagonals.into_iter().map(|d| d.collect()).collect()
+        };
+
+        let tall = grid(&["ab", "cd", "ef"]);
+        assert_eq!(collect(tall.diagonals().collect()), ["e", "cf", "ad", "b"]);
+        assert_eq!(
+            collect(tall.anti_diagonals().collect()),
+            ["a", "bc", "de", "f"]
+        );
+
+        let column = grid(&["a", "b"]);
+        assert_eq!(collect(column.anti_diagonals().collect()), ["a", "b"]);
+        assert_eq!(grid(&[]).diagonals().len(), 0);
+    }
+
+    #[test]
+    fn padding() {
+        let empty = grid(&[]);
+        let padded = empty.pad_surrounding('.');
+        assert_eq!(text(&padded), ["..", ".."]);
+        assert_eq!(text(&empty.pad(0, '.')), Vec::<String>::new());
+
+        let column = grid(&["a", "b", "c"]);
+        let padded = column.pad_surrounding('.');
+        assert_eq!((padded.x_max(), padded.y_max()), (3, 5));
+        assert_eq!(text(&padded), ["...", ".a.", ".b.", ".c.", "..."]);
+
+        let square = grid(&["ab", "cd"]);
+        let padded = square.pad(2, '.');
+        assert_eq!((padded.x_max(), padded.y_max()), (6, 6));
+        assert_eq!(padded.items().count(), 36);
+        assert_eq!(text(&padded)[2], "..ab..");
+
+        let sides = square.pad_sides(
+            Padding {
+                top: 1,
+                right: 2,
+                ..Padding::default()
+            },
+            '.',
+        );
+        assert_eq!(text(&sides), ["....", "ab..", "cd.."]);
+    }
+
+    #[test]
+    fn cropping() {
+        let square = grid(&["ab", "cd"]);
+        for n in 0..3 {
+            let trimmed = square.pad(n, '.').trim_where(|c| *c == '.');
+            assert_eq!(text(&trimmed), text(&square));
+        }
+
+        let column = grid(&["a", "b", "c"]);
+        assert_eq!(text(&column.trim_where(|c| *c != 'b')), ["b"]);
+        assert_eq!(text(&column.trim_where(|_| true)), Vec::<String>::new());
+        assert_eq!(
+            text(&column.crop(Pos2D::new(0, 1), Pos2D::new(1, 2)).unwrap()),
+            ["b", "c"]
+        );
+        assert!(column.crop(Pos2D::new(0, 1), Pos2D::new(1, 3)).is_none());
+
+        let empty = grid(&[]);
+        assert_eq!(empty.trim_where(|_| false).items().count(), 0);
+        assert!(empty.crop(Pos2D::new(0, 0), Pos2D::new(0, 0)).is_some());
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, GridCell)]
+    enum Cell {
+        #[cell('.', '@')]
+        Empty,
+        #[cell('#')]
+        Wall,
+        #[cell('[')]
+        LeftBox,
+        #[cell(']')]
+        RightBox,
+    }
+
+    #[test]
+    fn grid_cells() {
+        assert_eq!(Cell::try_from('@'), Ok(Cell::Empty));
+        assert_eq!(Cell::try_from('O'), Err(UnknownCell('O')));
+        assert_eq!(Cell::RightBox.to_string(), "]");
+
+        let (grid, markers) = RectangleGrid::<Cell>::parse_cells("##\n@.\n[]", &['@']).unwrap();
+        assert_eq!(markers.first('@'), Some(Pos2D::new(0, 1)));
+        assert_eq!(grid.at(Pos2D::new(1, 2)), Some(Cell::RightBox));
+        assert_eq!(grid.to_string(), "##\n..\n[]\n");
+
+        assert!(matches!(
+            RectangleGrid::<Cell>::parse_cells("#.\n.O", &[]),
+            Err(ParseCellError::UnknownCell { cell: 'O', pos }) if pos == Pos2D::new(1, 1)
+        ));
+        assert!(matches!(
+            RectangleGrid::<Cell>::parse_cells("#.\n.", &[]),
+            Err(ParseCellError::NonRectangle(_))
+        ));
+    }
+
+    #[test]
+    fn neighbors_and_fill() {
+        let maze = grid(&["#.#", "..#", "#.."]);
+
+        assert_eq!(maze.neighbors4(Pos2D::new(0, 0)).count(), 2);
+        assert_eq!(maze.neighbors8(Pos2D::new(1, 1)).count(), 8);
+        assert_eq!(maze.neighbors8(Pos2D::new(2, 2)).count(), 3);
+
+        let open = maze.flood_fill(Pos2D::new(1, 0), |_, c| *c == '.');
+        assert_eq!(open.len(), 5);
+        assert!(maze
+            .flood_fill(Pos2D::new(0, 0), |_, c| *c == '.')
+            .is_empty());
+
+        let regions = maze.label_regions(|lhs, rhs| lhs == rhs);
+        let mut sizes: Vec<_> = regions.iter().map(<[_]>::len).collect();
+        sizes.sort_unstable();
+        assert_eq!(sizes, [1, 1, 2, 5]);
+        assert_eq!(
+            regions.label(Pos2D::new(2, 0)),
+            regions.label(Pos2D::new(2, 1))
+        );
+    }
+}
@@ -1,12 +1,17 @@
 use std::{
     cmp::Ordering,
     fmt::Display,
+    hash::Hash,
     ops::{Add, Sub},
 };
 
-use num::{CheckedAdd, CheckedSub, Integer, Signed};
+use num::{traits::Euclid, CheckedAdd, CheckedSub, Integer, Signed};
 
-use crate::{grid::Pos2D, iter::ArrayIter};
+use crate::{
+    grid::{GridPos, Pos2D, RectangleGrid},
+    iter::ArrayIter,
+    posn::Pos,
+};
 
 /// Position in a 2D grid
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -153,6 +158,22 @@ where
     }
 }
 
+impl<N> GridPos for Pos2DExt<N>
+where
+    N: Integer + Copy + CheckedAdd + CheckedSub + Hash,
+    usize: TryFrom<N>,
+{
+    fn orthogonal(self) -> impl Iterator<Item = Self> {
+        [self.up(), self.right(), self.down(), self.left()]
+            .into_iter()
+            .flatten()
+    }
+
+    fn surrounding(self) -> impl Iterator<Item = Self> {
+        self.surrounding_pos()
+    }
+}
+
 impl<N> From<(N, N)> for Pos2DExt<N> {
     fn from(value: (N, N)) -> Self {
         Self {
@@ -162,6 +183,13 @@ impl<N> From<(N, N)> for Pos2DExt<N> {
     }
 }
 
+impl<N> From<Pos<N, 2>> for Pos2DExt<N> {
+    fn from(value: Pos<N, 2>) -> Self {
+        let [x, y] = value.coordinates;
+        Self { x, y }
+    }
+}
+
 impl<N: Add<Output = N>> Add for Pos2DExt<N> {
     type Output = Self;
 
@@ -181,6 +209,24 @@ impl<N: CheckedSub> Sub for Pos2DExt<N> {
     }
 }
 
+impl<N: Euclid> Pos2DExt<N> {
+    /// Wraps onto a `dims.x` by `dims.y` torus, using the Euclidean remainder
+    /// so each coordinate lands in `0..dims`.
+    ///
+    /// ```
+    /// use advent_rust_lib::grid_ext::Pos2DExt;
+    ///
+    /// let dims = Pos2DExt::new(11, 7);
+    /// assert_eq!(Pos2DExt::new(-1, 15).wrap_within(dims), Pos2DExt::new(10, 1));
+    /// ```
+    ///
+    /// # Panics
+    /// If either dimension is zero.
+    pub fn wrap_within(self, dims: Self) -> Self {
+        Self::new(self.x.rem_euclid(&dims.x), self.y.rem_euclid(&dims.y))
+    }
+}
+
 impl<N> Pos2DExt<N>
 where
     N: Signed + Copy + Sub<Output = N>,
@@ -342,3 +388,99 @@ where
         ))
     }
 }
+
+/// Access by signed positions.
+impl<T> RectangleGrid<T> {
+    /// Returns `None` for positions off the grid, including negative ones.
+    pub fn get_signed<N>(&self, pos: Pos2DExt<N>) -> Option<&T>
+    where
+        usize: TryFrom<N>,
+    {
+        self.get(Pos2D::try_from(pos).ok()?)
+    }
+
+    pub fn get_signed_mut<N>(&mut self, pos: Pos2DExt<N>) -> Option<&mut T>
+    where
+        usize: TryFrom<N>,
+    {
+        self.get_mut(Pos2D::try_from(pos).ok()?)
+    }
+
+    /// Positions wrapped onto this grid, treated as a torus.
+    fn wrap<N>(&self, pos: Pos2DExt<N>) -> Option<Pos2D>
+    where
+        N: Euclid + TryFrom<usize>,
+        usize: TryFrom<N>,
+    {
+        if self.x_max() == 0 || self.y_max() == 0 {
+            return None;
+        }
+        let dims = Pos2DExt::new(
+            N::try_from(self.x_max()).ok()?,
+            N::try_from(self.y_max()).ok()?,
+        );
+        Pos2D::try_from(pos.wrap_within(dims)).ok()
+    }
+
+    /// Treats the grid as a torus, so every position wraps onto a cell.
+    ///
+    /// Only returns `None` for an empty grid, or if the grid dimensions do
+    /// not fit in `N`.
+    ///
+    /// ```
+    /// use advent_rust_lib::{grid::RectangleGrid, grid_ext::Pos2DExt};
+    ///
+    /// let grid = RectangleGrid::try_from_iter(["ab".chars(), "cd".chars()]).unwrap();
+    ///
+    /// assert_eq!(grid.get_signed(Pos2DExt::new(-1_i64, 0)), None);
+    /// assert_eq!(grid.wrapping_get(Pos2DExt::new(-1_i64, 0)), Some(&'b'));
+    /// assert_eq!(grid.wrapping_get(Pos2DExt::new(4_i64, -3)), Some(&'c'));
+    /// ```
+    pub fn wrapping_get<N>(&self, pos: Pos2DExt<N>) -> Option<&T>
+    where
+        N: Euclid + TryFrom<usize>,
+        usize: TryFrom<N>,
+    {
+        self.get(self.wrap(pos)?)
+    }
+
+    pub fn wrapping_get_mut<N>(&mut self, pos: Pos2DExt<N>) -> Option<&mut T>
+    where
+        N: Euclid + TryFrom<usize>,
+        usize: TryFrom<N>,
+    {
+        let pos = self.wrap(pos)?;
+        self.get_mut(pos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wrapping() {
+        let dims = Pos2DExt::new(11_i64, 7);
+        assert_eq!(Pos2DExt::new(0, 0).wrap_within(dims), Pos2DExt::new(0, 0));
+        assert_eq!(
+            Pos2DExt::new(-11, -7).wrap_within(dims),
+            Pos2DExt::new(0, 0)
+        );
+        assert_eq!(
+            Pos2DExt::new(-12, 22).wrap_within(dims),
+            Pos2DExt::new(10, 1)
+        );
+
+        let mut grid = RectangleGrid::try_from_iter(["abc".chars(), "def".chars()]).unwrap();
+        assert_eq!(grid.get_signed(Pos2DExt::new(2_i64, 1)), Some(&'f'));
+        assert_eq!(grid.get_signed(Pos2DExt::new(3_i64, 1)), None);
+        assert_eq!(grid.get_signed(Pos2DExt::new(0_i64, -1)), None);
+
+        assert_eq!(grid.wrapping_get(Pos2DExt::new(-4_i32, -1)), Some(&'f'));
+        *grid.wrapping_get_mut(Pos2DExt::new(3_i64, 2)).unwrap() = 'z';
+        assert_eq!(grid.get(Pos2D::new(0, 0)), Some(&'z'));
+
+        let empty = RectangleGrid::<char>::default_with_dim(0, 0, '.');
+        assert_eq!(empty.wrapping_get(Pos2DExt::new(1_i64, 1)), None);
+    }
+}
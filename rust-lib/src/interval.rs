@@ -0,0 +1,399 @@
+//! Sets of integer intervals, and maps that translate them.
+//!
+//! ```
+//! use advent_rust_lib::interval::IntervalSet;
+//!
+//! let fresh: IntervalSet<u64> = [3..=5, 10..=14, 16..=20, 12..=18].into_iter().collect();
+//!
+//! assert_eq!(fresh.intervals().collect::<Vec<_>>(), [3..6, 10..21]);
+//! assert_eq!(fresh.total_len(), 14);
+//! assert_eq!(fresh.count_contained([1, 5, 8, 11, 17, 32]), 3);
+//! ```
+
+use std::{
+    cmp::{max, min},
+    ops::{Add, Range, RangeInclusive, Sub},
+};
+
+/// Integer usable as an interval bound.
+pub trait IntervalBound: Copy + Ord + Add<Output = Self> + Sub<Output = Self> {
+    const ZERO: Self;
+    const ONE: Self;
+}
+
+macro_rules! impl_interval_bound {
+    ($($ty:ty),*) => {
+        $(
+            impl IntervalBound for $ty {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+            }
+        )*
+    };
+}
+
+impl_interval_bound!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Sorted, disjoint intervals. Overlapping and touching intervals are merged
+/// on insert.
+///
+/// Stored half-open, so an inclusive interval ending at `T::MAX` overflows.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    // (start, exclusive end), with start < end
+    intervals: Vec<(T, T)>,
+}
+
+impl<T: IntervalBound> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self {
+            intervals: Vec::new(),
+        }
+    }
+
+    /// Merges overlapping and touching intervals, which must be sorted by
+    /// start.
+    fn from_sorted<I: IntoIterator<Item = (T, T)>>(sorted: I) -> Self {
+        let mut intervals: Vec<(T, T)> = Vec::new();
+
+        for (start, end) in sorted {
+            match intervals.last_mut() {
+                Some(last) if start <= last.1 => last.1 = max(last.1, end),
+                _ => intervals.push((start, end)),
+            }
+        }
+
+        Self { intervals }
+    }
+
+    /// Adds the half-open `range`, ignoring it if empty.
+    pub fn insert(&mut self, range: Range<T>) {
+        let Range { start, end } = range;
+        if start >= end {
+            return;
+        }
+
+        // Every interval overlapping or touching the new one
+        let first = self.intervals.partition_point(|(_, x)| *x < start);
+        let last = self.intervals.partition_point(|(x, _)| *x <= end);
+
+        let merged = if first < last {
+            (
+                min(start, self.intervals[first].0),
+                max(end, self.intervals[last - 1].1),
+            )
+        } else {
+            (start, end)
+        };
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    /// Adds the inclusive `range`, ignoring it if empty.
+    pub fn insert_inclusive(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+        if start <= end {
+            self.insert(start..end + T::ONE);
+        }
+    }
+
+    /// Each interval as a half-open range, in increasing order.
+    pub fn intervals(&self) -> impl ExactSizeIterator<Item = Range<T>> + '_ {
+        self.intervals.iter().map(|(start, end)| *start..*end)
+    }
+
+    /// Number of disjoint intervals.
+    pub fn interval_count(&self) -> usize {
+        self.intervals.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Number of values covered.
+    pub fn total_len(&self) -> T {
+        self.intervals
+            .iter()
+            .fold(T::ZERO, |total, (start, end)| total + (*end - *start))
+    }
+
+    /// Smallest value covered.
+    pub fn min(&self) -> Option<T> {
+        self.intervals.first().map(|(start, _)| *start)
+    }
+
+    /// Largest value covered.
+    pub fn max(&self) -> Option<T> {
+        self.intervals.last().map(|(_, end)| *end - T::ONE)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let idx = self.intervals.partition_point(|(_, end)| *end <= value);
+        self.intervals
+            .get(idx)
+            .is_some_and(|(start, _)| *start <= value)
+    }
+
+    /// Number of `values` covered, counting repeats.
+    pub fn count_contained<I: IntoIterator<Item = T>>(&self, values: I) -> usize {
+        values
+            .into_iter()
+            .filter(|value| self.contains(*value))
+            .count()
+    }
+
+    /// Values in either set.
+    pub fn union(&self, other: &Self) -> Self {
+        let mut combined: Vec<_> = self
+            .intervals
+            .iter()
+            .chain(&other.intervals)
+            .copied()
+            .collect();
+        combined.sort_unstable();
+
+        Self::from_sorted(combined)
+    }
+
+    /// Values in both sets.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intervals = Vec::new();
+        let (mut lhs, mut rhs) = (self.intervals.iter(), other.intervals.iter());
+        let (mut lhs_next, mut rhs_next) = (lhs.next(), rhs.next());
+
+        while let (Some(l), Some(r)) = (lhs_next, rhs_next) {
+            let start = max(l.0, r.0);
+            let end = min(l.1, r.1);
+            if start < end {
+                intervals.push((start, end));
+            }
+
+            // Advance whichever ends first
+            if l.1 <= r.1 {
+                lhs_next = lhs.next();
+            } else {
+                rhs_next = rhs.next();
+            }
+        }
+
+        Self { intervals }
+    }
+
+    /// Values in this set, but not `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut intervals = Vec::new();
+        let mut removed = other.intervals.iter().peekable();
+
+        for (start, end) in self.intervals.iter().copied() {
+            let mut cursor = start;
+
+            // Skip removals that end before this interval
+            while removed.next_if(|(_, x)| *x <= cursor).is_some() {}
+
+            while let Some((remove_start, remove_end)) = removed.peek().copied() {
+                if *remove_start >= end {
+                    break;
+                }
+                if cursor < *remove_start {
+                    intervals.push((cursor, *remove_start));
+                }
+                cursor = max(cursor, *remove_end);
+
+                if *remove_end > end {
+                    // May also cover the next interval
+                    break;
+                }
+                removed.next();
+            }
+
+            if cursor < end {
+                intervals.push((cursor, end));
+            }
+        }
+
+        Self { intervals }
+    }
+}
+
+impl<T: IntervalBound> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        let mut intervals: Vec<_> = iter
+            .into_iter()
+            .filter(|range| range.start < range.end)
+            .map(|range| (range.start, range.end))
+            .collect();
+        intervals.sort_unstable();
+
+        Self::from_sorted(intervals)
+    }
+}
+
+impl<T: IntervalBound> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        iter.into_iter()
+            .filter(|range| !range.is_empty())
+            .map(|range| {
+                let (start, end) = range.into_inner();
+                start..end + T::ONE
+            })
+            .collect()
+    }
+}
+
+// -------------------------------------------------- //
+
+/// Piecewise translation, moving every value in a source interval by the same
+/// offset. Values outside every source interval map to themselves.
+///
+/// ```
+/// use advent_rust_lib::interval::{IntervalSet, OffsetMap};
+///
+/// // seed-to-soil: 98..100 -> 50..52, 50..98 -> 52..100
+/// let mut soil = OffsetMap::new();
+/// soil.insert(98..100, 50);
+/// soil.insert(50..98, 52);
+///
+/// assert_eq!(soil.map(79), 81);
+/// assert_eq!(soil.map(14), 14);
+///
+/// let seeds: IntervalSet<u64> = [55..68].into_iter().collect();
+/// assert_eq!(soil.map_set(&seeds).intervals().collect::<Vec<_>>(), [57..70]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct OffsetMap<T> {
+    // (source start, exclusive source end, destination start), sorted by
+    // source start
+    pieces: Vec<(T, T, T)>,
+}
+
+impl<T: IntervalBound> OffsetMap<T> {
+    pub fn new() -> Self {
+        Self { pieces: Vec::new() }
+    }
+
+    /// Maps `source` onto the interval of the same length starting at
+    /// `dest_start`.
+    ///
+    /// Source intervals must not overlap.
+    pub fn insert(&mut self, source: Range<T>, dest_start: T) {
+        if source.start >= source.end {
+            return;
+        }
+
+        let idx = self
+            .pieces
+            .partition_point(|(start, _, _)| *start < source.start);
+        debug_assert!(
+            idx == 0 || self.pieces[idx - 1].1 <= source.start,
+            "overlapping source intervals"
+        );
+        debug_assert!(
+            self.pieces
+                .get(idx)
+                .is_none_or(|(start, _, _)| source.end <= *start),
+            "overlapping source intervals"
+        );
+
+        self.pieces
+            .insert(idx, (source.start, source.end, dest_start));
+    }
+
+    pub fn map(&self, value: T) -> T {
+        let idx = self.pieces.partition_point(|(_, end, _)| *end <= value);
+
+        match self.pieces.get(idx) {
+            Some((start, _, dest)) if *start <= value => *dest + (value - *start),
+            _ => value,
+        }
+    }
+
+    /// Maps every value in `set`.
+    pub fn map_set(&self, set: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut mapped = Vec::new();
+
+        for Range { start, end } in set.intervals() {
+            let mut cursor = start;
+            let first = self.pieces.partition_point(|(_, x, _)| *x <= start);
+
+            for (source_start, source_end, dest) in self.pieces[first..].iter().copied() {
+                if source_start >= end {
+                    break;
+                }
+                if cursor < source_start {
+                    mapped.push((cursor, source_start));
+                }
+
+                let overlap_start = max(cursor, source_start);
+                let overlap_end = min(end, source_end);
+                mapped.push((
+                    dest + (overlap_start - source_start),
+                    dest + (overlap_end - source_start),
+                ));
+                cursor = overlap_end;
+            }
+
+            if cursor < end {
+                mapped.push((cursor, end));
+            }
+        }
+
+        mapped.sort_unstable();
+        IntervalSet::from_sorted(mapped)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::single_range_in_vec_init)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[Range<i32>]) -> IntervalSet<i32> {
+        ranges.iter().cloned().collect()
+    }
+
+    #[test]
+    fn normalizing_insert() {
+        let mut intervals = IntervalSet::new();
+        intervals.insert(10..20);
+        intervals.insert(0..5);
+        intervals.insert(30..40);
+        intervals.insert_inclusive(5..=9);
+        intervals.insert(15..32);
+        intervals.insert(50..50);
+
+        assert_eq!(intervals, set(&[0..40]));
+        assert_eq!((intervals.min(), intervals.max()), (Some(0), Some(39)));
+        assert!(!intervals.contains(40));
+    }
+
+    #[test]
+    fn set_operations() {
+        let lhs = set(&[0..10, 20..30, 40..50]);
+        let rhs = set(&[5..25, 28..45]);
+
+        assert_eq!(lhs.union(&rhs), set(&[0..50]));
+        assert_eq!(
+            lhs.intersection(&rhs),
+            set(&[5..10, 20..25, 28..30, 40..45])
+        );
+        assert_eq!(lhs.difference(&rhs), set(&[0..5, 25..28, 45..50]));
+        assert_eq!(rhs.difference(&lhs), set(&[10..20, 30..40]));
+        assert_eq!(lhs.difference(&set(&[-5..60])), set(&[]));
+        assert_eq!(lhs.total_len(), 30);
+    }
+
+    #[test]
+    fn offset_map_splits() {
+        let mut map = OffsetMap::new();
+        map.insert(10..20, 100);
+        map.insert(25..30, -5);
+
+        assert_eq!(map.map(15), 105);
+        assert_eq!(map.map(22), 22);
+        assert_eq!(map.map(29), -1);
+        assert_eq!(
+            map.map_set(&set(&[0..40])),
+            set(&[-5..0, 0..10, 20..25, 30..40, 100..110])
+        );
+    }
+}
@@ -0,0 +1,369 @@
+//! Nearest neighbor queries over [`Pos`].
+//!
+//! ```
+//! use advent_rust_lib::{kd_tree::KdTree, posn::{Manhattan, Pos}};
+//!
+//! let tree = KdTree::new([[0_u64, 0], [10, 10], [1, 2], [9, 9]].map(Pos::new));
+//!
+//! assert_eq!(tree.nearest::<Manhattan>(&Pos::new([8, 8]), 2), [(3, 2), (1, 4)]);
+//! assert_eq!(tree.within::<Manhattan>(&Pos::new([0, 0]), 3), [(0, 0), (2, 3)]);
+//!
+//! let pairs: Vec<_> = tree.closest_pairs::<Manhattan>().take(2).collect();
+//! assert_eq!(pairs, [(1, 3, 2), (0, 2, 3)]);
+//! ```
+
+use std::{cmp::Reverse, collections::BinaryHeap, marker::PhantomData, ops::Sub};
+
+use crate::posn::{Metric, Pos};
+
+/// A k-d tree, splitting on each axis in turn.
+///
+/// Points are referred to by their index in the order they were given.
+/// Results with the same distance are ordered by index.
+#[derive(Debug, Clone)]
+pub struct KdTree<T, const N: usize> {
+    points: Vec<Pos<T, N>>,
+    // Indices into `points`, where each subtree is a contiguous slice with
+    // its splitting point in the middle
+    nodes: Vec<usize>,
+}
+
+/// Gathers points found by [`KdTree::search`].
+trait Collector<D> {
+    fn offer(&mut self, idx: usize, distance: D);
+
+    /// Distance beyond which nothing more will be collected, if any.
+    fn bound(&self) -> Option<D>;
+}
+
+struct Nearest<D> {
+    k: usize,
+    // Max heap, so the worst of the best is on top
+    best: BinaryHeap<(D, usize)>,
+}
+
+impl<D: Ord + Copy> Collector<D> for Nearest<D> {
+    fn offer(&mut self, idx: usize, distance: D) {
+        if self.best.len() < self.k {
+            self.best.push((distance, idx));
+        } else if self
+            .best
+            .peek()
+            .is_some_and(|worst| (distance, idx) < *worst)
+        {
+            self.best.pop();
+            self.best.push((distance, idx));
+        }
+    }
+
+    fn bound(&self) -> Option<D> {
+        (self.best.len() == self.k)
+            .then(|| self.best.peek().map(|(distance, _)| *distance))
+            .flatten()
+    }
+}
+
+struct Within<D> {
+    radius: D,
+    found: Vec<(D, usize)>,
+}
+
+impl<D: Ord + Copy> Collector<D> for Within<D> {
+    fn offer(&mut self, idx: usize, distance: D) {
+        if distance <= self.radius {
+            self.found.push((distance, idx));
+        }
+    }
+
+    fn bound(&self) -> Option<D> {
+        Some(self.radius)
+    }
+}
+
+impl<T, const N: usize> KdTree<T, N>
+where
+    T: Ord + Copy + Sub<Output = T>,
+{
+    pub fn new<I: IntoIterator<Item = Pos<T, N>>>(points: I) -> Self {
+        let points: Vec<_> = points.into_iter().collect();
+        let mut nodes: Vec<_> = (0..points.len()).collect();
+        Self::build(&points, &mut nodes, 0);
+        Self { points, nodes }
+    }
+
+    fn build(points: &[Pos<T, N>], nodes: &mut [usize], depth: usize) {
+        if nodes.len() <= 1 {
+            return;
+        }
+
+        let axis = depth % N;
+        let mid = nodes.len() / 2;
+        nodes.select_nth_unstable_by_key(mid, |idx| points[*idx].coordinates[axis]);
+
+        let (left, right) = nodes.split_at_mut(mid);
+        Self::build(points, left, depth + 1);
+        Self::build(points, &mut right[1..], depth + 1);
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Point at `idx` in the original order.
+    pub fn get(&self, idx: usize) -> Option<&Pos<T, N>> {
+        self.points.get(idx)
+    }
+
+    fn search<M, C>(&self, nodes: &[usize], depth: usize, target: &Pos<T, N>, collector: &mut C)
+    where
+        M: Metric<T, N>,
+        C: Collector<M::Distance>,
+    {
+        if nodes.is_empty() {
+            return;
+        }
+
+        let mid = nodes.len() / 2;
+        let point = &self.points[nodes[mid]];
+        collector.offer(nodes[mid], M::distance(target, point));
+
+        let axis = depth % N;
+        let (target_axis, point_axis) = (target.coordinates[axis], point.coordinates[axis]);
+        let (near, far, diff) = if target_axis < point_axis {
+            (&nodes[..mid], &nodes[mid + 1..], point_axis - target_axis)
+        } else {
+            (&nodes[mid + 1..], &nodes[..mid], target_axis - point_axis)
+        };
+
+        self.search::<M, C>(near, depth + 1, target, collector);
+        // Everything on the far side is at least `diff` away along this axis
+        if collector
+            .bound()
+            .is_none_or(|bound| M::axis_distance(diff) <= bound)
+        {
+            self.search::<M, C>(far, depth + 1, target, collector);
+        }
+    }
+
+    /// Up to `k` closest points to `target`, closest first, as
+    /// (index, distance).
+    pub fn nearest<M: Metric<T, N>>(
+        &self,
+        target: &Pos<T, N>,
+        k: usize,
+    ) -> Vec<(usize, M::Distance)> {
+        if k == 0 {
+            return Vec::new();
+        }
+
+        let mut collector = Nearest {
+            k,
+            best: BinaryHeap::with_capacity(k.min(self.len()) + 1),
+        };
+        self.search::<M, _>(&self.nodes, 0, target, &mut collector);
+
+        collector
+            .best
+            .into_sorted_vec()
+            .into_iter()
+            .map(|(distance, idx)| (idx, distance))
+            .collect()
+    }
+
+    /// Every point at most `radius` from `target`, closest first, as
+    /// (index, distance).
+    pub fn within<M: Metric<T, N>>(
+        &self,
+        target: &Pos<T, N>,
+        radius: M::Distance,
+    ) -> Vec<(usize, M::Distance)> {
+        let mut collector = Within {
+            radius,
+            found: Vec::new(),
+        };
+        self.search::<M, _>(&self.nodes, 0, target, &mut collector);
+
+        collector.found.sort_unstable();
+        collector
+            .found
+            .into_iter()
+            .map(|(distance, idx)| (idx, distance))
+            .collect()
+    }
+
+    /// Every pair of points, closest first, as (lower index, higher index,
+    /// distance).
+    ///
+    /// Neighbors are found lazily, so taking only the closest few pairs does
+    /// not compute the distance between every pair.
+    pub fn closest_pairs<M: Metric<T, N>>(&self) -> ClosestPairs<'_, T, N, M> {
+        ClosestPairs::new(self)
+    }
+}
+
+// ------------------------------------------------------------ //
+
+/// Neighbors of one point, fetched in growing batches.
+#[derive(Debug, Clone)]
+struct NeighborStream<D> {
+    batch: Vec<(usize, D)>,
+    requested: usize,
+    used: usize,
+}
+
+/// Iterator from [`KdTree::closest_pairs`].
+#[derive(Debug, Clone)]
+pub struct ClosestPairs<'a, T, const N: usize, M: Metric<T, N>> {
+    tree: &'a KdTree<T, N>,
+    streams: Vec<NeighborStream<M::Distance>>,
+    // Next pair from each point's stream, closest on top
+    next: BinaryHeap<Reverse<(M::Distance, usize, usize)>>,
+    metric: PhantomData<M>,
+}
+
+impl<'a, T, const N: usize, M> ClosestPairs<'a, T, N, M>
+where
+    T: Ord + Copy + Sub<Output = T>,
+    M: Metric<T, N>,
+{
+    fn new(tree: &'a KdTree<T, N>) -> Self {
+        let mut this = Self {
+            tree,
+            streams: vec![
+                NeighborStream {
+                    batch: Vec::new(),
+                    requested: 0,
+                    used: 0,
+                };
+                tree.len()
+            ],
+            next: BinaryHeap::with_capacity(tree.len()),
+            metric: PhantomData,
+        };
+
+        for idx in 0..tree.len() {
+            this.queue_next(idx);
+        }
+        this
+    }
+
+    /// Queues the next closest neighbor of `idx` with a higher index, so
+    /// each pair is only found once.
+    fn queue_next(&mut self, idx: usize) {
+        let stream = &mut self.streams[idx];
+        loop {
+            if let Some((other, distance)) = stream.batch.get(stream.used) {
+                stream.used += 1;
+                if *other > idx {
+                    self.next.push(Reverse((*distance, idx, *other)));
+                    return;
+                }
+            } else if stream.batch.len() < stream.requested {
+                // Already have every point
+                return;
+            } else {
+                // Nearest results are stable, so the used prefix is unchanged
+                stream.requested = (stream.requested * 2).max(4);
+                stream.batch = self
+                    .tree
+                    .nearest::<M>(&self.tree.points[idx], stream.requested);
+            }
+        }
+    }
+}
+
+impl<T, const N: usize, M> Iterator for ClosestPairs<'_, T, N, M>
+where
+    T: Ord + Copy + Sub<Output = T>,
+    M: Metric<T, N>,
+{
+    type Item = (usize, usize, M::Distance);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Reverse((distance, lhs, rhs)) = self.next.pop()?;
+        self.queue_next(lhs);
+        Some((lhs, rhs, distance))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::posn::{Chebyshev, SquaredEuclid};
+
+    use super::*;
+
+    // Deterministic scatter of points, with some duplicates
+    fn points() -> Vec<Pos<i64, 3>> {
+        let mut state = 7_i64;
+        let mut next = || {
+            state = (state * 1_103_515_245 + 12_345) % 2_147_483_648;
+            state % 50
+        };
+        (0..120)
+            .map(|_| Pos::new([next(), next(), next()]))
+            .collect()
+    }
+
+    fn brute_force<M: Metric<i64, 3>>(
+        points: &[Pos<i64, 3>],
+        target: &Pos<i64, 3>,
+    ) -> Vec<(usize, M::Distance)> {
+        let mut all: Vec<_> = points
+            .iter()
+            .enumerate()
+            .map(|(idx, point)| (M::distance(target, point), idx))
+            .collect();
+        all.sort_unstable();
+        all.into_iter()
+            .map(|(distance, idx)| (idx, distance))
+            .collect()
+    }
+
+    #[test]
+    fn queries_match_brute_force() {
+        let points = points();
+        let tree = KdTree::new(points.iter().copied());
+
+        for target in [Pos::new([0, 0, 0]), Pos::new([25, 10, 40]), points[17]] {
+            let all = brute_force::<SquaredEuclid>(&points, &target);
+            assert_eq!(tree.nearest::<SquaredEuclid>(&target, 10), all[..10]);
+            assert_eq!(tree.nearest::<SquaredEuclid>(&target, 500), all);
+            assert_eq!(tree.nearest::<SquaredEuclid>(&target, usize::MAX), all);
+
+            let all = brute_force::<Chebyshev>(&points, &target);
+            let close: Vec<_> = all.into_iter().filter(|(_, d)| *d <= 12).collect();
+            assert_eq!(tree.within::<Chebyshev>(&target, 12), close);
+        }
+    }
+
+    #[test]
+    fn closest_pairs_in_order() {
+        let points = points();
+        let tree = KdTree::new(points.iter().copied());
+
+        let mut all: Vec<_> = (0..points.len())
+            .flat_map(|lhs| (lhs + 1..points.len()).map(move |rhs| (lhs, rhs)))
+            .map(|(lhs, rhs)| (points[lhs].squared_euclid(&points[rhs]), lhs, rhs))
+            .collect();
+        all.sort_unstable();
+
+        let pairs: Vec<_> = tree.closest_pairs::<SquaredEuclid>().collect();
+        assert_eq!(pairs.len(), all.len());
+        assert!(pairs
+            .iter()
+            .zip(&all)
+            .all(|((_, _, lhs), (rhs, _, _))| lhs == rhs));
+        assert!(pairs.iter().all(|(lhs, rhs, _)| lhs < rhs));
+
+        assert_eq!(
+            KdTree::<i64, 3>::new([])
+                .closest_pairs::<SquaredEuclid>()
+                .count(),
+            0
+        );
+    }
+}
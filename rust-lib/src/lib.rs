@@ -1,17 +1,40 @@
 //! Advent of Code helper lib.
 
+// Lets derived code refer to `::advent_rust_lib` inside this crate too
+extern crate self as advent_rust_lib;
+
+pub mod automaton;
+pub mod bit_grid;
 pub mod direction;
 pub mod grid;
+pub mod interval;
 pub mod iter;
 pub mod ll;
+pub mod memo;
+pub mod mst;
+pub mod parse;
 pub mod read;
+pub mod search;
 pub mod signed;
+pub mod solution;
+pub mod union_find;
 
+#[cfg(feature = "num")]
+pub mod compress;
 #[cfg(feature = "num")]
 pub mod gcd;
 #[cfg(feature = "num")]
 pub mod grid_ext;
 #[cfg(feature = "num")]
+pub mod kd_tree;
+#[cfg(feature = "num")]
+pub mod polygon;
+#[cfg(feature = "num")]
 pub mod posn;
 #[cfg(feature = "num")]
 pub mod signed_ext;
+#[cfg(feature = "num")]
+pub mod sparse_grid;
+
+#[cfg(feature = "render")]
+pub mod render;
@@ -0,0 +1,315 @@
+//! Caches for recursive counting problems.
+//!
+//! ```
+//! use advent_rust_lib::memo::Memo;
+//!
+//! // Ways to build "brwrr" from the towels "r", "wr", "b", "br"
+//! let towels = ["r", "wr", "b", "br"];
+//! let design = "brwrr";
+//!
+//! let mut memo = Memo::dense(design.len() + 1);
+//! let ways: u64 = memo.solve(0, &|recurse, start: &usize| {
+//!     if *start == design.len() {
+//!         return 1;
+//!     }
+//!     towels
+//!         .iter()
+//!         .filter(|towel| design[*start..].starts_with(**towel))
+//!         .map(|towel| recurse(start + towel.len()))
+//!         .sum()
+//! });
+//!
+//! assert_eq!(ways, 2);
+//! ```
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    hash::Hash,
+    marker::PhantomData,
+};
+
+/// Storage for [`Memo`].
+pub trait Cache<K, V> {
+    /// Takes `&mut self` so that lookups can update recency.
+    fn get(&mut self, key: &K) -> Option<&V>;
+    fn insert(&mut self, key: K, value: V);
+    fn len(&self) -> usize;
+    fn clear(&mut self);
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<K: Eq + Hash, V> Cache<K, V> for HashMap<K, V> {
+    fn get(&mut self, key: &K) -> Option<&V> {
+        HashMap::get(self, key)
+    }
+
+    fn insert(&mut self, key: K, value: V) {
+        HashMap::insert(self, key, value);
+    }
+
+    fn len(&self) -> usize {
+        HashMap::len(self)
+    }
+
+    fn clear(&mut self) {
+        HashMap::clear(self)
+    }
+}
+
+/// Keeps at most `capacity` values, dropping the least recently used.
+#[derive(Debug, Clone)]
+pub struct LruCache<K, V> {
+    capacity: usize,
+    // Value and the tick it was last used
+    values: HashMap<K, (V, u64)>,
+    by_use: BTreeMap<u64, K>,
+    tick: u64,
+}
+
+impl<K, V> LruCache<K, V> {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            values: HashMap::new(),
+            by_use: BTreeMap::new(),
+            tick: 0,
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
+
+impl<K: Clone + Eq + Hash, V> Cache<K, V> for LruCache<K, V> {
+    fn get(&mut self, key: &K) -> Option<&V> {
+        let (value, last_used) = self.values.get_mut(key)?;
+
+        self.tick += 1;
+        let key = self
+            .by_use
+            .remove(last_used)
+            .expect("every value has a use entry");
+        *last_used = self.tick;
+        self.by_use.insert(self.tick, key);
+
+        Some(value)
+    }
+
+    fn insert(&mut self, key: K, value: V) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        self.tick += 1;
+        if let Some((_, last_used)) = self.values.remove(&key) {
+            self.by_use.remove(&last_used);
+        } else if self.values.len() == self.capacity {
+            let (_, oldest) = self
+                .by_use
+                .pop_first()
+                .expect("a full cache has a use entry");
+            self.values.remove(&oldest);
+        }
+
+        self.by_use.insert(self.tick, key.clone());
+        self.values.insert(key, (value, self.tick));
+    }
+
+    fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    fn clear(&mut self) {
+        self.values.clear();
+        self.by_use.clear();
+    }
+}
+
+/// Array-backed storage for small, dense `usize` keys. Grows to fit.
+#[derive(Debug, Clone)]
+pub struct DenseCache<V> {
+    values: Vec<Option<V>>,
+    len: usize,
+}
+
+impl<V> DenseCache<V> {
+    /// Preallocates keys `0..len`.
+    pub fn new(len: usize) -> Self {
+        Self {
+            values: std::iter::repeat_with(|| None).take(len).collect(),
+            len: 0,
+        }
+    }
+}
+
+impl<V> Cache<usize, V> for DenseCache<V> {
+    fn get(&mut self, key: &usize) -> Option<&V> {
+        self.values.get(*key)?.as_ref()
+    }
+
+    fn insert(&mut self, key: usize, value: V) {
+        if key >= self.values.len() {
+            self.values.resize_with(key + 1, || None);
+        }
+        if self.values[key].replace(value).is_none() {
+            self.len += 1;
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn clear(&mut self) {
+        self.values.iter_mut().for_each(|value| *value = None);
+        self.len = 0;
+    }
+}
+
+// -------------------------------------------------- //
+
+/// Remembers the value computed for each key.
+#[derive(Debug, Clone)]
+pub struct Memo<K, V, C = HashMap<K, V>> {
+    cache: C,
+    _types: PhantomData<fn(K) -> V>,
+}
+
+impl<K: Eq + Hash, V> Memo<K, V> {
+    pub fn new() -> Self {
+        Self::with_cache(HashMap::new())
+    }
+}
+
+impl<K: Eq + Hash, V> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Clone + Eq + Hash, V> Memo<K, V, LruCache<K, V>> {
+    /// Keeps at most `capacity` values, dropping the least recently used.
+    pub fn bounded(capacity: usize) -> Self {
+        Self::with_cache(LruCache::new(capacity))
+    }
+}
+
+impl<V> Memo<usize, V, DenseCache<V>> {
+    /// Array-backed, preallocating keys `0..len`.
+    pub fn dense(len: usize) -> Self {
+        Self::with_cache(DenseCache::new(len))
+    }
+}
+
+impl<K, V, C: Cache<K, V>> Memo<K, V, C> {
+    pub fn with_cache(cache: C) -> Self {
+        Self {
+            cache,
+            _types: PhantomData,
+        }
+    }
+
+    pub fn cache(&self) -> &C {
+        &self.cache
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.cache.clear()
+    }
+
+    /// The cached value for `key`, computing it with `compute` if missing.
+    pub fn get_or_insert_with<F>(&mut self, key: K, compute: F) -> V
+    where
+        V: Clone,
+        F: FnOnce(&K) -> V,
+    {
+        if let Some(value) = self.cache.get(&key) {
+            return value.clone();
+        }
+
+        let value = compute(&key);
+        self.cache.insert(key, value.clone());
+        value
+    }
+
+    /// The cached value for `key`, computing it with `compute` if missing.
+    ///
+    /// `compute` is given a function to solve, and cache, sub-problems.
+    pub fn solve<F>(&mut self, key: K, compute: &F) -> V
+    where
+        V: Clone,
+        F: Fn(&mut dyn FnMut(K) -> V, &K) -> V,
+    {
+        if let Some(value) = self.cache.get(&key) {
+            return value.clone();
+        }
+
+        let value = compute(&mut |sub_key| self.solve(sub_key, compute), &key);
+        self.cache.insert(key, value.clone());
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+
+    fn fib<C: Cache<usize, u64>>(
+        memo: &mut Memo<usize, u64, C>,
+        n: usize,
+        calls: &Cell<u32>,
+    ) -> u64 {
+        memo.solve(n, &|recurse, n: &usize| {
+            calls.set(calls.get() + 1);
+            match n {
+                0 | 1 => *n as u64,
+                _ => recurse(n - 1) + recurse(n - 2),
+            }
+        })
+    }
+
+    #[test]
+    fn each_key_once() {
+        let calls = Cell::new(0);
+
+        assert_eq!(fib(&mut Memo::new(), 90, &calls), 2880067194370816120);
+        assert_eq!(calls.get(), 91);
+
+        calls.set(0);
+        let mut memo = Memo::dense(4);
+        assert_eq!(fib(&mut memo, 90, &calls), 2880067194370816120);
+        assert_eq!((calls.get(), memo.len()), (91, 91));
+    }
+
+    #[test]
+    fn bounded_eviction() {
+        let mut memo = Memo::bounded(2);
+        memo.get_or_insert_with(1, |_| "one");
+        memo.get_or_insert_with(2, |_| "two");
+        // Touch 1, so that 2 is the oldest
+        memo.get_or_insert_with(1, |_| unreachable!());
+        memo.get_or_insert_with(3, |_| "three");
+
+        assert_eq!(memo.len(), 2);
+        assert_eq!(memo.get_or_insert_with(1, |_| "recomputed"), "one");
+        assert_eq!(memo.get_or_insert_with(2, |_| "recomputed"), "recomputed");
+
+        // Recursion still completes when the cache is too small to hold it
+        let calls = Cell::new(0);
+        assert_eq!(fib(&mut Memo::bounded(8), 30, &calls), 832040);
+    }
+}
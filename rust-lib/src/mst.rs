@@ -0,0 +1,270 @@
+//! Minimum spanning trees, by Kruskal's and Prim's algorithms.
+//!
+//! ```
+//! use advent_rust_lib::mst::{prim, Edge, Kruskal};
+//!
+//! // A square with one diagonal
+//! let edges = [(0, 1, 4), (1, 2, 1), (2, 3, 3), (3, 0, 2), (0, 2, 5)]
+//!     .map(|(lhs, rhs, weight)| Edge::new(lhs, rhs, weight));
+//!
+//! let mut kruskal = Kruskal::new(4, edges);
+//! let first = kruskal.next().unwrap();
+//! assert_eq!((first.edge, first.size, first.components), (edges[1], 2, 3));
+//!
+//! let rest: Vec<_> = kruskal.map(|step| step.edge.weight).collect();
+//! assert_eq!(rest, [2, 3]);
+//!
+//! let weights = [[0, 4, 5, 2], [4, 0, 1, 0], [5, 1, 0, 3], [2, 0, 3, 0]];
+//! let tree = prim(4, |lhs, rhs| (weights[lhs][rhs] > 0).then_some(weights[lhs][rhs]));
+//! assert_eq!(tree.iter().map(|edge| edge.weight).sum::<i32>(), 6);
+//! ```
+
+use std::vec;
+
+use crate::union_find::IndexUnionFind;
+
+#[cfg(feature = "num")]
+use std::ops::Sub;
+
+#[cfg(feature = "num")]
+use crate::{
+    kd_tree::KdTree,
+    posn::{Metric, Pos},
+};
+
+/// Weighted edge between the vertices `lhs` and `rhs`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Edge<W> {
+    pub lhs: usize,
+    pub rhs: usize,
+    pub weight: W,
+}
+
+impl<W> Edge<W> {
+    pub fn new(lhs: usize, rhs: usize, weight: W) -> Self {
+        Self { lhs, rhs, weight }
+    }
+}
+
+/// Edge accepted by [`Kruskal`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Step<W> {
+    pub edge: Edge<W>,
+    /// Size of the component the edge just formed.
+    pub size: usize,
+    /// Number of components left after accepting the edge.
+    pub components: usize,
+}
+
+/// Kruskal's algorithm, yielding each edge of the minimum spanning forest
+/// as it is accepted.
+///
+/// Stops once every vertex is connected, so lazily sorted edges are only
+/// read as far as needed.
+#[derive(Debug, Clone)]
+pub struct Kruskal<I> {
+    edges: I,
+    sets: IndexUnionFind,
+}
+
+impl<W: Ord> Kruskal<vec::IntoIter<Edge<W>>> {
+    /// Spanning tree over the vertices `0..len`, sorting `edges` first.
+    ///
+    /// Edges with the same weight are considered in the order given.
+    pub fn new<E: IntoIterator<Item = Edge<W>>>(len: usize, edges: E) -> Self {
+        let mut edges: Vec<_> = edges.into_iter().collect();
+        edges.sort_by(|lhs, rhs| lhs.weight.cmp(&rhs.weight));
+        Self::from_sorted(len, edges)
+    }
+}
+
+impl<W, I> Kruskal<I>
+where
+    I: Iterator<Item = Edge<W>>,
+{
+    /// Spanning tree over the vertices `0..len`, from edges already sorted
+    /// by weight.
+    pub fn from_sorted<E: IntoIterator<IntoIter = I>>(len: usize, edges: E) -> Self {
+        Self {
+            edges: edges.into_iter(),
+            sets: IndexUnionFind::new(len),
+        }
+    }
+
+    /// Components formed by the edges accepted so far.
+    pub fn sets(&self) -> &IndexUnionFind {
+        &self.sets
+    }
+
+    /// Considers the next `count` edges, whether or not they are accepted.
+    pub fn consider(&mut self, count: usize) {
+        for edge in self.edges.by_ref().take(count) {
+            self.sets.union(edge.lhs, edge.rhs);
+        }
+    }
+}
+
+impl<W, I> Iterator for Kruskal<I>
+where
+    I: Iterator<Item = Edge<W>>,
+{
+    type Item = Step<W>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.sets.component_count() > 1 {
+            let edge = self.edges.next()?;
+            if self.sets.union(edge.lhs, edge.rhs) {
+                return Some(Step {
+                    size: self.sets.size(edge.lhs),
+                    components: self.sets.component_count(),
+                    edge,
+                });
+            }
+        }
+        None
+    }
+}
+
+/// [`Kruskal`] over every pair of points in `tree`, with vertices numbered
+/// as in the tree.
+#[cfg(feature = "num")]
+pub fn kruskal_points<'a, T, const N: usize, M>(
+    tree: &'a KdTree<T, N>,
+) -> Kruskal<impl Iterator<Item = Edge<M::Distance>> + 'a>
+where
+    T: Ord + Copy + Sub<Output = T>,
+    M: Metric<T, N> + 'a,
+{
+    let edges = tree
+        .closest_pairs::<M>()
+        .map(|(lhs, rhs, distance)| Edge::new(lhs, rhs, distance));
+    Kruskal::from_sorted(tree.len(), edges)
+}
+
+// ------------------------------------------------------------ //
+
+/// Prim's algorithm over the vertices `0..len`, in `O(len^2)` calls to
+/// `weight`, for dense graphs.
+///
+/// `weight` gives the weight of the edge between two vertices, if any.
+/// Returns the edges of the minimum spanning forest, each with `lhs`
+/// already in the tree when it was added.
+pub fn prim<W, F>(len: usize, mut weight: F) -> Vec<Edge<W>>
+where
+    W: Ord + Copy,
+    F: FnMut(usize, usize) -> Option<W>,
+{
+    let mut in_tree = vec![false; len];
+    // Cheapest known edge into each vertex, as (weight, vertex in the tree)
+    let mut best: Vec<Option<(W, usize)>> = vec![None; len];
+    let mut edges = Vec::with_capacity(len.saturating_sub(1));
+
+    for _ in 0..len {
+        // Vertices with no edge into the tree start a new tree
+        let next = (0..len)
+            .filter(|idx| !in_tree[*idx])
+            .min_by_key(|idx| (best[*idx].is_none(), best[*idx]))
+            .expect("Each round adds one vertex");
+        in_tree[next] = true;
+        if let Some((weight, from)) = best[next] {
+            edges.push(Edge::new(from, next, weight));
+        }
+
+        for other in (0..len).filter(|idx| !in_tree[*idx]) {
+            if let Some(weight) = weight(next, other) {
+                if best[other].is_none_or(|(current, _)| weight < current) {
+                    best[other] = Some((weight, next));
+                }
+            }
+        }
+    }
+
+    edges
+}
+
+/// [`prim`] over the complete graph of `points`, weighted by `M`.
+#[cfg(feature = "num")]
+pub fn prim_points<T, const N: usize, M>(points: &[Pos<T, N>]) -> Vec<Edge<M::Distance>>
+where
+    M: Metric<T, N>,
+{
+    prim(points.len(), |lhs, rhs| {
+        Some(M::distance(&points[lhs], &points[rhs]))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn forest() {
+        // Two triangles with no edge between them
+        let edges = [
+            (0, 1, 3),
+            (1, 2, 1),
+            (0, 2, 2),
+            (3, 4, 1),
+            (4, 5, 5),
+            (3, 5, 1),
+        ]
+        .map(|(lhs, rhs, weight)| Edge::new(lhs, rhs, weight));
+
+        let steps: Vec<_> = Kruskal::new(6, edges).collect();
+        let sizes: Vec<_> = steps.iter().map(|step| step.size).collect();
+        let components: Vec<_> = steps.iter().map(|step| step.components).collect();
+        assert_eq!(sizes, [2, 2, 3, 3]);
+        assert_eq!(components, [5, 4, 3, 2]);
+
+        let mut kruskal = Kruskal::new(6, edges);
+        kruskal.consider(3);
+        assert_eq!(kruskal.sets().component_count(), 3);
+        assert_eq!(kruskal.map(|step| step.edge.weight).sum::<i32>(), 2);
+
+        let weight = |lhs, rhs| {
+            edges
+                .iter()
+                .find(|edge| {
+                    (edge.lhs, edge.rhs) == (lhs, rhs) || (edge.rhs, edge.lhs) == (lhs, rhs)
+                })
+                .map(|edge| edge.weight)
+        };
+        let tree = prim(6, weight);
+        assert_eq!(tree.len(), 4);
+        assert_eq!(tree.iter().map(|edge| edge.weight).sum::<i32>(), 5);
+
+        assert_eq!(Kruskal::<vec::IntoIter<Edge<i32>>>::new(0, []).count(), 0);
+        assert!(prim(1, |_, _| Some(0)).is_empty());
+    }
+
+    #[cfg(feature = "num")]
+    #[test]
+    fn points() {
+        use crate::posn::{Manhattan, SquaredEuclid};
+
+        let mut state = 11_i64;
+        let mut next = || {
+            state = (state * 1_103_515_245 + 12_345) % 2_147_483_648;
+            state % 100
+        };
+        let points: Vec<_> = (0..80).map(|_| Pos::new([next(), next()])).collect();
+        let tree = KdTree::new(points.iter().copied());
+
+        let steps: Vec<_> = kruskal_points::<_, 2, SquaredEuclid>(&tree).collect();
+        assert_eq!(steps.len(), points.len() - 1);
+        assert_eq!(steps.last().map(|step| step.size), Some(points.len()));
+        assert!(steps
+            .windows(2)
+            .all(|pair| pair[0].edge.weight <= pair[1].edge.weight));
+
+        let kruskal: Vec<_> = kruskal_points::<_, 2, Manhattan>(&tree)
+            .map(|step| step.edge.weight)
+            .collect();
+        let prim: Vec<_> = prim_points::<_, 2, Manhattan>(&points)
+            .iter()
+            .map(|edge| edge.weight)
+            .collect();
+        assert_eq!(prim.len(), kruskal.len());
+        assert_eq!(prim.iter().sum::<i64>(), kruskal.iter().sum::<i64>());
+    }
+}
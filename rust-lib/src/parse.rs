@@ -0,0 +1,355 @@
+use std::{fmt::Display, str::FromStr};
+
+use thiserror::Error;
+
+/// Parsing failure at a 1-indexed line and column of the input.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("line {line}, column {column}: {kind}")]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub kind: ParseErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ParseErrorKind {
+    #[error("could not parse \"{text}\": {reason}")]
+    Invalid { text: String, reason: String },
+    #[error("no line labeled \"{0}\"")]
+    MissingLabel(String),
+    #[error("expected {expected} numbers, found {found}")]
+    Count { expected: usize, found: usize },
+}
+
+/// Part of the input that remembers where it started, for error reporting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Section<'a> {
+    text: &'a str,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> Section<'a> {
+    /// The full input, starting at line 1.
+    pub fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            line: 1,
+            column: 1,
+        }
+    }
+
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    /// Line of the input this section starts on.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Column of the input this section starts on.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// Column of the byte `offset` on this section's first line.
+    fn column_at(&self, offset: usize) -> usize {
+        self.column + self.text[..offset].chars().count()
+    }
+
+    fn error(&self, offset: usize, kind: ParseErrorKind) -> ParseError {
+        ParseError {
+            line: self.line,
+            column: self.column_at(offset),
+            kind,
+        }
+    }
+
+    fn invalid<E: Display>(&self, offset: usize, text: &str, reason: E) -> ParseError {
+        self.error(
+            offset,
+            ParseErrorKind::Invalid {
+                text: text.to_string(),
+                reason: reason.to_string(),
+            },
+        )
+    }
+
+    /// Each line as its own section.
+    pub fn lines(&self) -> impl Iterator<Item = Section<'a>> + 'a {
+        let Self { text, line, column } = *self;
+
+        text.lines().enumerate().map(move |(idx, text)| Self {
+            text,
+            line: line + idx,
+            column: if idx == 0 { column } else { 1 },
+        })
+    }
+
+    /// Groups of lines separated by blank lines.
+    pub fn sections(&self) -> impl Iterator<Item = Section<'a>> + 'a {
+        let text = self.text;
+        let mut lines = self.lines().peekable();
+
+        std::iter::from_fn(move || {
+            while lines.next_if(|line| line.text.trim().is_empty()).is_some() {}
+
+            let first = lines.next()?;
+            let mut last = first;
+            while let Some(line) = lines.next_if(|line| !line.text.trim().is_empty()) {
+                last = line;
+            }
+
+            let start = first.text.as_ptr() as usize - text.as_ptr() as usize;
+            let end = last.text.as_ptr() as usize - text.as_ptr() as usize + last.text.len();
+            Some(Section {
+                text: &text[start..end],
+                ..first
+            })
+        })
+    }
+
+    /// Parses the whole section, ignoring surrounding whitespace.
+    pub fn parse<T>(&self) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let trimmed = self.text.trim();
+        let offset = self.text.len() - self.text.trim_start().len();
+
+        trimmed
+            .parse()
+            .map_err(|e| self.invalid(offset, trimmed, e))
+    }
+
+    fn numbers<T>(&self, signed: bool) -> Result<Vec<T>, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let mut numbers = Vec::new();
+
+        for line in self.lines() {
+            let bytes = line.text.as_bytes();
+            let mut idx = 0;
+
+            while idx < bytes.len() {
+                if !bytes[idx].is_ascii_digit() {
+                    idx += 1;
+                    continue;
+                }
+
+                let mut start = idx;
+                if signed && start > 0 && bytes[start - 1] == b'-' {
+                    // "1-2" is a range, not 1 and -2
+                    if start < 2 || !bytes[start - 2].is_ascii_digit() {
+                        start -= 1;
+                    }
+                }
+                while idx < bytes.len() && bytes[idx].is_ascii_digit() {
+                    idx += 1;
+                }
+
+                let number = &line.text[start..idx];
+                numbers.push(number.parse().map_err(|e| line.invalid(start, number, e))?);
+            }
+        }
+
+        Ok(numbers)
+    }
+
+    /// Every run of digits, in order.
+    ///
+    /// ```
+    /// use advent_rust_lib::parse::Section;
+    ///
+    /// let stones = Section::new("125 17\n3-4").ints::<u64>().unwrap();
+    /// assert_eq!(stones, [125, 17, 3, 4]);
+    /// ```
+    pub fn ints<T>(&self) -> Result<Vec<T>, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.numbers(false)
+    }
+
+    /// Every run of digits, negative when directly preceded by a `-` that is
+    /// not itself preceded by a digit.
+    ///
+    /// ```
+    /// use advent_rust_lib::parse::Section;
+    ///
+    /// let robot = Section::new("p=0,4 v=3,-3").signed_ints::<i64>().unwrap();
+    /// assert_eq!(robot, [0, 4, 3, -3]);
+    /// ```
+    pub fn signed_ints<T>(&self) -> Result<Vec<T>, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.numbers(true)
+    }
+
+    fn array<T, const N: usize>(&self, numbers: Vec<T>) -> Result<[T; N], ParseError> {
+        let found = numbers.len();
+        numbers
+            .try_into()
+            .map_err(|_| self.error(0, ParseErrorKind::Count { expected: N, found }))
+    }
+
+    /// [`Self::ints`], requiring exactly `N` numbers.
+    ///
+    /// ```
+    /// use advent_rust_lib::parse::Section;
+    ///
+    /// let [x, y] = Section::new("Prize: X=8400, Y=5400").int_array::<u64, 2>().unwrap();
+    /// assert_eq!((x, y), (8400, 5400));
+    /// ```
+    pub fn int_array<T, const N: usize>(&self) -> Result<[T; N], ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.array(self.ints()?)
+    }
+
+    /// [`Self::signed_ints`], requiring exactly `N` numbers.
+    pub fn signed_int_array<T, const N: usize>(&self) -> Result<[T; N], ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.array(self.signed_ints()?)
+    }
+
+    /// The text after `label:` on the first line starting with `label`.
+    pub fn labeled_section(&self, label: &str) -> Result<Section<'a>, ParseError> {
+        self.lines()
+            .find_map(|line| {
+                let after_indent = line.text.trim_start();
+                let value = after_indent.strip_prefix(label)?.strip_prefix(':')?;
+
+                Some(Section {
+                    text: value,
+                    column: line.column_at(line.text.len() - value.len()),
+                    ..line
+                })
+            })
+            .ok_or_else(|| self.error(0, ParseErrorKind::MissingLabel(label.to_string())))
+    }
+
+    /// Parses the value of a `label: value` line.
+    ///
+    /// ```
+    /// use advent_rust_lib::parse::Section;
+    ///
+    /// let registers = Section::new("Register A: 729\nRegister B: 0");
+    /// assert_eq!(registers.labeled::<u64>("Register A").unwrap(), 729);
+    /// assert!(registers.labeled::<u64>("Register C").is_err());
+    /// ```
+    pub fn labeled<T>(&self, label: &str) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.labeled_section(label)?.parse()
+    }
+}
+
+/// Groups of lines separated by blank lines.
+pub fn sections(input: &str) -> impl Iterator<Item = Section<'_>> {
+    Section::new(input).sections()
+}
+
+/// Every run of digits in `input`, in order.
+pub fn ints<T>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    Section::new(input).ints()
+}
+
+/// Every run of digits in `input`, with `-` signs.
+pub fn signed_ints<T>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    Section::new(input).signed_ints()
+}
+
+/// Parses the value of a `label: value` line in `input`.
+pub fn labeled<T>(input: &str, label: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    Section::new(input).labeled(label)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REGISTERS: &str = "Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0
+";
+
+    #[test]
+    fn split_sections() {
+        let parts: Vec<_> = sections(REGISTERS).collect();
+
+        assert_eq!(parts.len(), 2);
+        assert_eq!(
+            parts[0].text(),
+            "Register A: 729\nRegister B: 0\nRegister C: 0"
+        );
+        assert_eq!(parts[1].line(), 5);
+        assert_eq!(
+            parts[1].labeled_section("Program").unwrap().ints::<u8>(),
+            Ok(vec![0, 1, 5, 4, 3, 0])
+        );
+    }
+
+    #[test]
+    fn error_positions() {
+        let err = ints::<u8>("1 2\n3 256").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+
+        let err = labeled::<u8>(REGISTERS, "Program").unwrap_err();
+        assert_eq!((err.line, err.column), (5, 10));
+
+        let err = Section::new("1\n2,3")
+            .lines()
+            .nth(1)
+            .unwrap()
+            .int_array::<u8, 3>()
+            .unwrap_err();
+        assert_eq!(
+            (err.line, err.kind),
+            (
+                2,
+                ParseErrorKind::Count {
+                    expected: 3,
+                    found: 2
+                }
+            )
+        );
+
+        let err = labeled::<u8>(REGISTERS, "Register D").unwrap_err();
+        assert_eq!(err.kind, ParseErrorKind::MissingLabel("Register D".into()));
+    }
+
+    #[test]
+    fn signs() {
+        assert_eq!(signed_ints::<i32>("-1 x-2 3-4"), Ok(vec![-1, -2, 3, 4]));
+        assert_eq!(ints::<u32>("-1 x-2 3-4"), Ok(vec![1, 2, 3, 4]));
+    }
+}
@@ -0,0 +1,456 @@
+//! Simple polygons on the integer lattice.
+//!
+//! Orientations are as drawn with y increasing downwards, like the rest of
+//! this crate.
+//!
+//! ```
+//! use advent_rust_lib::{grid_ext::Pos2DExt, polygon::{Orientation, Polygon}};
+//!
+//! // An L made of 3x3 and 2x2 blocks of tiles, walked clockwise on screen
+//! let corners = [(0, 0), (2, 0), (2, 2), (4, 2), (4, 4), (0, 4)];
+//! let polygon: Polygon<i64> = corners.into_iter().map(Pos2DExt::from).collect();
+//!
+//! assert_eq!(polygon.orientation(), Orientation::Clockwise);
+//! assert_eq!(polygon.twice_signed_area(), 24);
+//! assert_eq!(polygon.boundary_count(), 16);
+//! assert_eq!(polygon.interior_count(), 5);
+//! assert_eq!(polygon.lattice_count(), 21);
+//!
+//! assert!(polygon.contains(Pos2DExt::new(4, 3)));
+//! assert!(!polygon.contains(Pos2DExt::new(3, 1)));
+//! assert!(polygon.contains_rect(Pos2DExt::new(0, 2), Pos2DExt::new(4, 4)));
+//! assert!(!polygon.contains_rect(Pos2DExt::new(0, 0), Pos2DExt::new(4, 2)));
+//!
+//! let tiles = polygon.tiles();
+//! assert!(tiles.contains_rect(Pos2DExt::new(0, 0), Pos2DExt::new(2, 4)));
+//! assert!(!tiles.contains(Pos2DExt::new(3, 1)));
+//! ```
+
+use num::{Integer, Signed};
+
+use crate::{
+    compress::CoordCompressor,
+    grid::{Grid, Pos2D},
+    grid_ext::Pos2DExt,
+};
+
+/// Direction a polygon's vertices are listed in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    Clockwise,
+    CounterClockwise,
+    /// No area, e.g. fewer than three vertices or all of them in a line.
+    Degenerate,
+}
+
+/// A simple polygon, with an edge between each consecutive pair of vertices
+/// and from the last vertex back to the first.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Polygon<N> {
+    vertices: Vec<Pos2DExt<N>>,
+}
+
+impl<N, V> FromIterator<V> for Polygon<N>
+where
+    V: Into<Pos2DExt<N>>,
+{
+    fn from_iter<I: IntoIterator<Item = V>>(iter: I) -> Self {
+        Self::new(iter.into_iter().map(Into::into).collect())
+    }
+}
+
+impl<N> Polygon<N> {
+    pub fn new(vertices: Vec<Pos2DExt<N>>) -> Self {
+        Self { vertices }
+    }
+
+    pub fn vertices(&self) -> &[Pos2DExt<N>] {
+        &self.vertices
+    }
+}
+
+impl<N> Polygon<N>
+where
+    N: Integer + Signed + Copy,
+{
+    /// Each edge as (start, end), including the closing edge.
+    pub fn edges(&self) -> impl Iterator<Item = (Pos2DExt<N>, Pos2DExt<N>)> + '_ {
+        self.vertices
+            .iter()
+            .zip(self.vertices.iter().cycle().skip(1))
+            .map(|(start, end)| (*start, *end))
+    }
+
+    /// Shoelace formula, doubled so it is always an integer.
+    ///
+    /// Positive for clockwise polygons and negative for counter-clockwise.
+    pub fn twice_signed_area(&self) -> N {
+        self.edges()
+            .map(|(start, end)| start.x * end.y - end.x * start.y)
+            .fold(N::zero(), |acc, cross| acc + cross)
+    }
+
+    /// Area of the region enclosed by the edges, rounded down.
+    pub fn area(&self) -> N {
+        self.twice_signed_area().abs() / (N::one() + N::one())
+    }
+
+    pub fn orientation(&self) -> Orientation {
+        let area = self.twice_signed_area();
+        if area.is_positive() {
+            Orientation::Clockwise
+        } else if area.is_negative() {
+            Orientation::CounterClockwise
+        } else {
+            Orientation::Degenerate
+        }
+    }
+
+    /// Number of lattice points on the edges.
+    pub fn boundary_count(&self) -> N {
+        self.edges()
+            .map(|(start, end)| (end.x - start.x).abs().gcd(&(end.y - start.y).abs()))
+            .fold(N::zero(), |acc, points| acc + points)
+    }
+
+    /// Number of lattice points strictly inside, by Pick's theorem.
+    pub fn interior_count(&self) -> N {
+        let two = N::one() + N::one();
+        (self.twice_signed_area().abs() - self.boundary_count() + two) / two
+    }
+
+    /// Number of lattice points inside or on the edges.
+    ///
+    /// When vertices are the centers of grid tiles, this is the number of
+    /// tiles covered.
+    pub fn lattice_count(&self) -> N {
+        self.interior_count() + self.boundary_count()
+    }
+
+    /// True if every edge is horizontal or vertical.
+    pub fn is_rectilinear(&self) -> bool {
+        self.edges()
+            .all(|(start, end)| start.x == end.x || start.y == end.y)
+    }
+
+    /// True if `point` is inside or on an edge.
+    pub fn contains(&self, point: Pos2DExt<N>) -> bool {
+        let two = N::one() + N::one();
+        self.contains_doubled(Pos2DExt::new(point.x * two, point.y * two))
+    }
+
+    /// [`Self::contains`] against this polygon scaled up by two, so points
+    /// halfway between lattice points can be tested.
+    fn contains_doubled(&self, point: Pos2DExt<N>) -> bool {
+        let two = N::one() + N::one();
+        let mut inside = false;
+
+        for (start, end) in self.edges() {
+            let start = Pos2DExt::new(start.x * two, start.y * two);
+            let end = Pos2DExt::new(end.x * two, end.y * two);
+
+            let cross =
+                (end.x - start.x) * (point.y - start.y) - (point.x - start.x) * (end.y - start.y);
+            let within = |p: N, a: N, b: N| a.min(b) <= p && p <= a.max(b);
+            if cross.is_zero() && within(point.x, start.x, end.x) && within(point.y, start.y, end.y)
+            {
+                return true;
+            }
+
+            // Ray cast to the right, counting edges that straddle the point's
+            // row and cross it to the right of the point
+            if (start.y > point.y) != (end.y > point.y) {
+                let crosses_right = if end.y > start.y {
+                    cross.is_positive()
+                } else {
+                    cross.is_negative()
+                };
+                if crosses_right {
+                    inside = !inside;
+                }
+            }
+        }
+
+        inside
+    }
+
+    /// True if the rectangle with opposite corners `corner` and `other` is
+    /// entirely inside or on the edges of this polygon.
+    ///
+    /// Only correct for rectilinear polygons. This checks the continuous
+    /// region, so a one wide notch between two edges fails the check even
+    /// though no lattice point in it is outside. To check covered grid tiles
+    /// instead, see [`Self::tiles`].
+    pub fn contains_rect(&self, corner: Pos2DExt<N>, other: Pos2DExt<N>) -> bool {
+        debug_assert!(self.is_rectilinear());
+
+        let (x_min, x_max) = (corner.x.min(other.x), corner.x.max(other.x));
+        let (y_min, y_max) = (corner.y.min(other.y), corner.y.max(other.y));
+
+        if x_min == x_max || y_min == y_max {
+            return self.contains_line(Pos2DExt::new(x_min, y_min), Pos2DExt::new(x_max, y_max));
+        }
+
+        // With no edge passing through the open interior, the interior is
+        // either entirely inside or entirely outside
+        let crosses_interior = self.edges().any(|(start, end)| {
+            if start.x == end.x {
+                x_min < start.x
+                    && start.x < x_max
+                    && start.y.min(end.y) < y_max
+                    && start.y.max(end.y) > y_min
+            } else {
+                y_min < start.y
+                    && start.y < y_max
+                    && start.x.min(end.x) < x_max
+                    && start.x.max(end.x) > x_min
+            }
+        });
+
+        !crosses_interior && self.contains_doubled(Pos2DExt::new(x_min + x_max, y_min + y_max))
+    }
+
+    /// [`Self::contains_rect`] for a horizontal or vertical line.
+    fn contains_line(&self, start: Pos2DExt<N>, end: Pos2DExt<N>) -> bool {
+        let horizontal = start.y == end.y;
+        let along = |pos: Pos2DExt<N>| if horizontal { pos.x } else { pos.y };
+        let at = |value: N| {
+            if horizontal {
+                Pos2DExt::new(value, start.y)
+            } else {
+                Pos2DExt::new(start.x, value)
+            }
+        };
+
+        // Being inside can only change where the line meets a vertex
+        // coordinate, so check those and each midpoint between them
+        let (low, high) = (along(start), along(end));
+        let mut stops: Vec<_> = self
+            .vertices
+            .iter()
+            .map(|vertex| along(*vertex))
+            .filter(|value| low < *value && *value < high)
+            .chain([low, high])
+            .collect();
+        stops.sort_unstable();
+        stops.dedup();
+
+        let two = N::one() + N::one();
+        stops.iter().all(|stop| self.contains(at(*stop)))
+            && stops.windows(2).all(|pair| {
+                let doubled = at(pair[0] + pair[1]);
+                let doubled = if horizontal {
+                    Pos2DExt::new(doubled.x, doubled.y * two)
+                } else {
+                    Pos2DExt::new(doubled.x * two, doubled.y)
+                };
+                self.contains_doubled(doubled)
+            })
+    }
+
+    /// Grid tiles on or inside this polygon, when vertices are the centers of
+    /// tiles.
+    ///
+    /// Only correct for rectilinear polygons.
+    pub fn tiles(&self) -> Tiles<N> {
+        debug_assert!(self.is_rectilinear());
+
+        // Padding keeps a ring of outside cells around the polygon
+        let compressor = CoordCompressor::with_gaps(self.vertices.iter().flat_map(|pos| {
+            [
+                *pos,
+                Pos2DExt::new(pos.x - N::one(), pos.y - N::one()),
+                Pos2DExt::new(pos.x + N::one(), pos.y + N::one()),
+            ]
+        }));
+        let compress = |pos| compressor.compress(pos).expect("Vertices are compressed");
+
+        let mut walls = compressor.grid(false);
+        for (start, end) in self.edges() {
+            let (start, end) = (compress(start), compress(end));
+            for x in start.x.min(end.x)..=start.x.max(end.x) {
+                for y in start.y.min(end.y)..=start.y.max(end.y) {
+                    *walls.get_mut(Pos2D::new(x, y)).unwrap() = true;
+                }
+            }
+        }
+        let outside = walls.flood_fill(Pos2D::new(0, 0), |_, wall| !wall);
+
+        let (x_len, y_len) = (walls.x_max(), walls.y_max());
+        let mut outside_before = vec![vec![0; x_len + 1]; y_len + 1];
+        for y in 0..y_len {
+            for x in 0..x_len {
+                outside_before[y + 1][x + 1] = outside_before[y][x + 1] + outside_before[y + 1][x]
+                    - outside_before[y][x]
+                    + usize::from(outside.contains(&Pos2D::new(x, y)));
+            }
+        }
+
+        Tiles {
+            compressor,
+            outside_before,
+        }
+    }
+}
+
+// ------------------------------------------------------------ //
+
+/// Grid tiles covered by a rectilinear polygon, from [`Polygon::tiles`].
+///
+/// Built once so that many rectangles can be checked quickly. Unlike
+/// [`Polygon::contains_rect`], a one wide notch between two edges is
+/// covered, since every tile in it is on an edge.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tiles<N> {
+    compressor: CoordCompressor<N>,
+    // Number of outside compressed cells above and to the left of each cell
+    outside_before: Vec<Vec<usize>>,
+}
+
+impl<N: Integer + Copy> Tiles<N> {
+    /// True if `tile` is on or inside the polygon.
+    pub fn contains(&self, tile: Pos2DExt<N>) -> bool {
+        self.contains_rect(tile, tile)
+    }
+
+    /// True if every tile in the rectangle with opposite corners `corner` and
+    /// `other` is on or inside the polygon.
+    pub fn contains_rect(&self, corner: Pos2DExt<N>, other: Pos2DExt<N>) -> bool {
+        // Anything past the padding is outside
+        let (Some(lhs), Some(rhs)) = (
+            self.compressor.compress(corner),
+            self.compressor.compress(other),
+        ) else {
+            return false;
+        };
+
+        let (x_low, x_high) = (lhs.x.min(rhs.x), lhs.x.max(rhs.x) + 1);
+        let (y_low, y_high) = (lhs.y.min(rhs.y), lhs.y.max(rhs.y) + 1);
+        let before = &self.outside_before;
+        before[y_high][x_high] + before[y_low][x_low]
+            == before[y_low][x_high] + before[y_high][x_low]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn polygon(corners: &[(i64, i64)]) -> Polygon<i64> {
+        corners.iter().copied().map(Pos2DExt::from).collect()
+    }
+
+    #[test]
+    fn areas() {
+        let square = polygon(&[(0, 0), (0, 3), (3, 3), (3, 0)]);
+        assert_eq!(square.orientation(), Orientation::CounterClockwise);
+        assert_eq!(square.twice_signed_area(), -18);
+        assert_eq!(square.area(), 9);
+        assert_eq!(square.lattice_count(), 16);
+
+        let triangle = polygon(&[(0, 0), (4, 0), (0, 3)]);
+        assert_eq!(triangle.area(), 6);
+        assert_eq!(triangle.boundary_count(), 4 + 3 + 1);
+        assert_eq!(triangle.interior_count(), 3);
+        assert!(!triangle.is_rectilinear());
+
+        assert_eq!(
+            polygon(&[(0, 0), (5, 0)]).orientation(),
+            Orientation::Degenerate
+        );
+    }
+
+    #[test]
+    fn containment() {
+        // A U with a 1 wide gap at x = 2, open at the top
+        let u = polygon(&[
+            (0, 0),
+            (1, 0),
+            (1, 2),
+            (3, 2),
+            (3, 0),
+            (4, 0),
+            (4, 4),
+            (0, 4),
+        ]);
+
+        assert!(u.contains(Pos2DExt::new(1, 1)));
+        assert!(u.contains(Pos2DExt::new(2, 3)));
+        assert!(!u.contains(Pos2DExt::new(2, 1)));
+        assert!(!u.contains(Pos2DExt::new(5, 1)));
+
+        assert!(u.contains_rect(Pos2DExt::new(0, 2), Pos2DExt::new(4, 4)));
+        assert!(u.contains_rect(Pos2DExt::new(3, 0), Pos2DExt::new(4, 4)));
+        // Over the gap
+        assert!(!u.contains_rect(Pos2DExt::new(0, 0), Pos2DExt::new(4, 4)));
+        assert!(!u.contains_rect(Pos2DExt::new(1, 0), Pos2DExt::new(3, 2)));
+        // Lines along and across the gap
+        assert!(u.contains_rect(Pos2DExt::new(0, 2), Pos2DExt::new(4, 2)));
+        assert!(!u.contains_rect(Pos2DExt::new(0, 1), Pos2DExt::new(4, 1)));
+        assert!(!u.contains_rect(Pos2DExt::new(1, 0), Pos2DExt::new(3, 0)));
+        assert!(u.contains_rect(Pos2DExt::new(1, 1), Pos2DExt::new(1, 1)));
+
+        // Every lattice point is inside, but the notch between x = 1 and 2 is not
+        let notch = polygon(&[
+            (0, 0),
+            (1, 0),
+            (1, 2),
+            (2, 2),
+            (2, 0),
+            (4, 0),
+            (4, 4),
+            (0, 4),
+        ]);
+        assert!((0..=4).all(|x| (0..=4).all(|y| notch.contains(Pos2DExt::new(x, y)))));
+        assert!(!notch.contains_rect(Pos2DExt::new(0, 0), Pos2DExt::new(4, 4)));
+    }
+
+    #[test]
+    fn tiles() {
+        let u = polygon(&[
+            (0, 0),
+            (1, 0),
+            (1, 2),
+            (3, 2),
+            (3, 0),
+            (4, 0),
+            (4, 4),
+            (0, 4),
+        ]);
+        let notch = polygon(&[
+            (0, 0),
+            (1, 0),
+            (1, 2),
+            (2, 2),
+            (2, 0),
+            (4, 0),
+            (4, 4),
+            (0, 4),
+        ]);
+
+        // Every rectangle near each polygon, against its lattice points
+        for shape in [u, notch] {
+            let tiles = shape.tiles();
+            let range = || -1..=5;
+            for (x_low, x_high) in range().flat_map(|low| range().map(move |high| (low, high))) {
+                for (y_low, y_high) in range().flat_map(|low| range().map(move |high| (low, high)))
+                {
+                    let all_inside = (x_low.min(x_high)..=x_low.max(x_high)).all(|x| {
+                        (y_low.min(y_high)..=y_low.max(y_high))
+                            .all(|y| shape.contains(Pos2DExt::new(x, y)))
+                    });
+                    let (corner, other) =
+                        (Pos2DExt::new(x_low, y_low), Pos2DExt::new(x_high, y_high));
+                    assert_eq!(tiles.contains_rect(corner, other), all_inside);
+                }
+            }
+        }
+
+        // Tiles in the gaps between vertex coordinates
+        let square = polygon(&[(0, 0), (1000, 0), (1000, 1000), (0, 1000)]).tiles();
+        assert!(square.contains_rect(Pos2DExt::new(999, 1), Pos2DExt::new(1, 500)));
+        assert!(!square.contains_rect(Pos2DExt::new(500, 500), Pos2DExt::new(500, 1001)));
+        assert!(!square.contains(Pos2DExt::new(-5000, 0)));
+        assert!(!polygon(&[]).tiles().contains(Pos2DExt::new(0, 0)));
+    }
+}
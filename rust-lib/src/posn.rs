@@ -3,10 +3,10 @@ use std::{
     cmp::Ordering,
     fmt::{Debug, Display},
     iter::{Product, Sum},
-    ops::{Add, AddAssign, Mul, Sub},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
 
-use num::{cast::AsPrimitive, CheckedSub};
+use num::{cast::AsPrimitive, CheckedSub, Zero};
 
 /// Position in a N-dimensional grid
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -72,6 +72,59 @@ impl<T: AddAssign + Add<Output = T>, const N: usize> Add for Pos<T, N> {
     }
 }
 
+/// Underflows like `T` does, e.g. panicking in debug builds for unsigned `T`.
+impl<T: SubAssign, const N: usize> Sub for Pos<T, N> {
+    type Output = Self;
+
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        self.coordinates
+            .iter_mut()
+            .zip(rhs.coordinates)
+            .for_each(|(lhs, rhs)| *lhs -= rhs);
+        self
+    }
+}
+
+/// Scales every coordinate.
+impl<T: MulAssign + Copy, const N: usize> Mul<T> for Pos<T, N> {
+    type Output = Self;
+
+    fn mul(mut self, rhs: T) -> Self::Output {
+        self.coordinates.iter_mut().for_each(|dim| *dim *= rhs);
+        self
+    }
+}
+
+/// Divides every coordinate.
+impl<T: DivAssign + Copy, const N: usize> Div<T> for Pos<T, N> {
+    type Output = Self;
+
+    fn div(mut self, rhs: T) -> Self::Output {
+        self.coordinates.iter_mut().for_each(|dim| *dim /= rhs);
+        self
+    }
+}
+
+impl<T: Neg<Output = T>, const N: usize> Neg for Pos<T, N> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(self.coordinates.map(Neg::neg))
+    }
+}
+
+impl<T, const N: usize> Pos<T, N>
+where
+    T: Zero + Copy + Mul<Output = T>,
+{
+    pub fn dot(&self, other: &Self) -> T {
+        self.coordinates
+            .iter()
+            .zip(&other.coordinates)
+            .fold(T::zero(), |acc, (lhs, rhs)| acc + *lhs * *rhs)
+    }
+}
+
 impl<T, const N: usize> Pos<T, N>
 where
     T: Sum + AsPrimitive<f32>,
@@ -113,6 +166,100 @@ where
     }
 }
 
+/// Exact distances, which work for both signed and unsigned `T`.
+impl<T, const N: usize> Pos<T, N>
+where
+    T: Zero + Mul<Output = T> + Sub<Output = T> + Ord + Copy,
+{
+    /// Non-negative difference along each axis.
+    fn axis_diffs<'a>(&'a self, other: &'a Self) -> impl Iterator<Item = T> + 'a {
+        self.coordinates
+            .iter()
+            .zip(&other.coordinates)
+            .map(|(lhs, rhs)| *lhs.max(rhs) - *lhs.min(rhs))
+    }
+
+    /// Square of the Euclidean distance, which orders the same way.
+    pub fn squared_euclid(&self, other: &Self) -> T {
+        self.axis_diffs(other)
+            .fold(T::zero(), |acc, diff| acc + diff * diff)
+    }
+
+    /// Sum of the differences along each axis.
+    pub fn manhattan(&self, other: &Self) -> T {
+        self.axis_diffs(other)
+            .fold(T::zero(), |acc, diff| acc + diff)
+    }
+
+    /// Largest difference along any axis.
+    pub fn chebyshev(&self, other: &Self) -> T {
+        self.axis_diffs(other).fold(T::zero(), T::max)
+    }
+
+    /// Distance under the metric `M`.
+    ///
+    /// ```
+    /// use advent_rust_lib::posn::{Chebyshev, Manhattan, Pos, SquaredEuclid};
+    ///
+    /// let (lhs, rhs) = (Pos::new([1_u64, 5]), Pos::new([4, 1]));
+    ///
+    /// assert_eq!(lhs.distance::<SquaredEuclid>(&rhs), 25);
+    /// assert_eq!(lhs.distance::<Manhattan>(&rhs), 7);
+    /// assert_eq!(lhs.distance::<Chebyshev>(&rhs), 4);
+    /// ```
+    pub fn distance<M: Metric<T, N>>(&self, other: &Self) -> M::Distance {
+        M::distance(self, other)
+    }
+}
+
+// ------------------------------------------------------------ //
+
+/// An exact distance between positions, which can be sorted directly.
+pub trait Metric<T, const N: usize> {
+    type Distance: Ord + Copy;
+
+    fn distance(lhs: &Pos<T, N>, rhs: &Pos<T, N>) -> Self::Distance;
+
+    /// Smallest distance between two positions that are `diff` apart along
+    /// one axis, for pruning searches.
+    fn axis_distance(diff: T) -> Self::Distance;
+}
+
+/// See [`Pos::squared_euclid`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SquaredEuclid;
+
+/// See [`Pos::manhattan`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Manhattan;
+
+/// See [`Pos::chebyshev`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Chebyshev;
+
+macro_rules! impl_metric {
+    ($metric:ty, $method:ident, |$diff:ident| $axis_distance:expr) => {
+        impl<T, const N: usize> Metric<T, N> for $metric
+        where
+            T: Zero + Mul<Output = T> + Sub<Output = T> + Ord + Copy,
+        {
+            type Distance = T;
+
+            fn distance(lhs: &Pos<T, N>, rhs: &Pos<T, N>) -> T {
+                lhs.$method(rhs)
+            }
+
+            fn axis_distance($diff: T) -> T {
+                $axis_distance
+            }
+        }
+    };
+}
+
+impl_metric!(SquaredEuclid, squared_euclid, |diff| diff * diff);
+impl_metric!(Manhattan, manhattan, |diff| diff);
+impl_metric!(Chebyshev, chebyshev, |diff| diff);
+
 impl<T, const N: usize> Pos<T, N>
 where
     T: for<'a> Product<&'a T>,
@@ -121,3 +268,47 @@ where
         self.coordinates.iter().product()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let lhs = Pos::new([4_i64, -2, 6]);
+        let rhs = Pos::new([1, 3, -1]);
+
+        assert_eq!(lhs - rhs, Pos::new([3, -5, 7]));
+        assert_eq!(lhs * 2, Pos::new([8, -4, 12]));
+        assert_eq!(lhs / 2, Pos::new([2, -1, 3]));
+        assert_eq!(-lhs, Pos::new([-4, 2, -6]));
+        assert_eq!(lhs.dot(&rhs), 4 - 6 - 6);
+        assert_eq!(lhs - rhs + rhs, lhs);
+    }
+
+    #[test]
+    fn distances() {
+        // Far enough apart that f32 can't tell the two distances apart
+        let origin = Pos::new([0_u64, 0, 0]);
+        let near = Pos::new([100_000_000, 0, 0]);
+        let far = Pos::new([100_000_001, 0, 0]);
+        assert_eq!(origin.euclid_dist(&near), origin.euclid_dist(&far));
+        assert!(origin.squared_euclid(&near) < origin.squared_euclid(&far));
+
+        let lhs = Pos::new([3_u64, 10, 7]);
+        let rhs = Pos::new([5, 4, 7]);
+        assert_eq!(lhs.squared_euclid(&rhs), 4 + 36);
+        assert_eq!(lhs.manhattan(&rhs), 8);
+        assert_eq!(lhs.chebyshev(&rhs), 6);
+        assert_eq!(
+            lhs.distance::<Manhattan>(&rhs),
+            rhs.distance::<Manhattan>(&lhs)
+        );
+
+        let lhs = Pos::new([-3_i64, 2]);
+        let rhs = Pos::new([1, -4]);
+        assert_eq!(lhs.squared_euclid(&rhs), 16 + 36);
+        assert_eq!(lhs.manhattan(&rhs), 10);
+        assert_eq!(rhs.chebyshev(&lhs), 6);
+    }
+}
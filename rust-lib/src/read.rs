@@ -1,10 +1,20 @@
 use std::{
+    borrow::Cow,
+    collections::HashMap,
     env::args,
-    fs::File,
-    io::{BufRead, BufReader},
+    fmt::Display,
+    fs::{read_to_string, File},
+    io::{self, read_to_string as read_all, stdin, BufRead, BufReader},
+    path::PathBuf,
+    str::FromStr,
 };
 
+use thiserror::Error;
+
 /// Return input lines (given as command arguments)
+///
+/// Panics if a file cannot be read. Kept for the standalone binaries that
+/// take positional arguments, prefer [`Input`] elsewhere.
 pub fn input() -> impl Iterator<Item = String> {
     args().skip(1).flat_map(|arg| {
         BufReader::new(File::open(arg).unwrap())
@@ -16,6 +26,8 @@ pub fn input() -> impl Iterator<Item = String> {
 /// Return given input lines (given as command arguments)
 ///
 /// * idxs: List of arg indicies to read
+///
+/// Panics like [`input`].
 pub fn filtered_input(valid_idxs: &[usize]) -> impl Iterator<Item = String> + use<'_> {
     args()
         .enumerate()
@@ -28,3 +40,292 @@ pub fn filtered_input(valid_idxs: &[usize]) -> impl Iterator<Item = String> + us
                 .map(|line| line.unwrap())
         })
 }
+
+// -------------------------------------------------- //
+
+/// Named values that are not part of the puzzle text (e.g. a grid size).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params {
+    inner: HashMap<String, String>,
+}
+
+#[derive(Debug, Error)]
+pub enum ParamError {
+    #[error("Missing parameter --{0}")]
+    Missing(String),
+    #[error("Could not parse --{name} \"{value}\": {reason}")]
+    Invalid {
+        name: String,
+        value: String,
+        reason: String,
+    },
+}
+
+impl Params {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert<N: Into<String>, V: Into<String>>(&mut self, name: N, value: V) {
+        self.inner.insert(name.into(), value.into());
+    }
+
+    pub fn with<N: Into<String>, V: Into<String>>(mut self, name: N, value: V) -> Self {
+        self.insert(name, value);
+        self
+    }
+
+    pub fn remove(&mut self, name: &str) -> Option<String> {
+        self.inner.remove(name)
+    }
+
+    pub fn raw(&self, name: &str) -> Option<&str> {
+        self.inner.get(name).map(String::as_str)
+    }
+
+    /// Parse the parameter `name`, failing if it was not given.
+    pub fn get<T>(&self, name: &str) -> Result<T, ParamError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.get_opt(name)?
+            .ok_or_else(|| ParamError::Missing(name.to_string()))
+    }
+
+    /// Parse the parameter `name`, if it was given.
+    pub fn get_opt<T>(&self, name: &str) -> Result<Option<T>, ParamError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.raw(name)
+            .map(|value| {
+                value.parse().map_err(|e: T::Err| ParamError::Invalid {
+                    name: name.to_string(),
+                    value: value.to_string(),
+                    reason: e.to_string(),
+                })
+            })
+            .transpose()
+    }
+}
+
+// -------------------------------------------------- //
+
+/// Where one piece of the puzzle text comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    Path(PathBuf),
+    Text(Cow<'static, str>),
+}
+
+impl Source {
+    pub fn read(&self) -> Result<Cow<'_, str>, InputError> {
+        match self {
+            Self::Stdin => read_all(stdin()).map(Cow::Owned).map_err(InputError::Stdin),
+            Self::Path(path) => {
+                read_to_string(path)
+                    .map(Cow::Owned)
+                    .map_err(|source| InputError::Path {
+                        path: path.clone(),
+                        source,
+                    })
+            }
+            Self::Text(text) => Ok(Cow::Borrowed(text)),
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum InputError {
+    #[error("Could not read {}: {source}", path.display())]
+    Path { path: PathBuf, source: io::Error },
+    #[error("Could not read stdin: {0}")]
+    Stdin(io::Error),
+    #[error("--{0} is missing a value")]
+    MissingValue(String),
+}
+
+/// Puzzle text and the parameters to solve it with.
+///
+/// Sources are read in the order they were added. With no sources, stdin is
+/// read instead.
+///
+/// ```
+/// use advent_rust_lib::read::Input;
+///
+/// let input = Input::new().text("1,2\n3,4").param("dim", "7");
+///
+/// assert_eq!(input.lines().unwrap().count(), 2);
+/// assert_eq!(input.params().get::<usize>("dim").unwrap(), 7);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Input {
+    sources: Vec<Source>,
+    params: Params,
+}
+
+impl Input {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn stdin(mut self) -> Self {
+        self.sources.push(Source::Stdin);
+        self
+    }
+
+    pub fn path<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.sources.push(Source::Path(path.into()));
+        self
+    }
+
+    /// In-memory puzzle text, e.g. an example in a test.
+    pub fn text<S: Into<String>>(mut self, text: S) -> Self {
+        self.sources.push(Source::Text(Cow::Owned(text.into())));
+        self
+    }
+
+    /// Puzzle text compiled into the binary with `include_str!`.
+    pub fn embedded(mut self, text: &'static str) -> Self {
+        self.sources.push(Source::Text(Cow::Borrowed(text)));
+        self
+    }
+
+    pub fn param<N: Into<String>, V: Into<String>>(mut self, name: N, value: V) -> Self {
+        self.params.insert(name, value);
+        self
+    }
+
+    /// Parse command line arguments.
+    ///
+    /// Bare arguments and `--input` are paths (`-` is stdin), and any other
+    /// `--name value` pair becomes a parameter.
+    pub fn from_args<I, S>(args: I) -> Result<Self, InputError>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut this = Self::default();
+        let mut args = args.into_iter().map(Into::into);
+
+        while let Some(arg) = args.next() {
+            let path = if let Some(name) = arg.strip_prefix("--") {
+                let value = args
+                    .next()
+                    .ok_or_else(|| InputError::MissingValue(name.to_string()))?;
+
+                if name != "input" {
+                    this.params.insert(name, value);
+                    continue;
+                }
+                value
+            } else {
+                arg
+            };
+
+            this = if path == "-" {
+                this.stdin()
+            } else {
+                this.path(path)
+            };
+        }
+
+        Ok(this)
+    }
+
+    /// [`Self::from_args`] on this process's arguments.
+    pub fn from_env() -> Result<Self, InputError> {
+        Self::from_args(args().skip(1))
+    }
+
+    pub fn sources(&self) -> &[Source] {
+        &self.sources
+    }
+
+    pub fn params(&self) -> &Params {
+        &self.params
+    }
+
+    pub fn params_mut(&mut self) -> &mut Params {
+        &mut self.params
+    }
+
+    /// Reads every source, joining them with newlines.
+    pub fn read_to_string(&self) -> Result<String, InputError> {
+        if self.sources.is_empty() {
+            return Source::Stdin.read().map(Cow::into_owned);
+        }
+
+        let mut text = String::new();
+        for source in &self.sources {
+            if !(text.is_empty() || text.ends_with('\n')) {
+                text.push('\n');
+            }
+            text.push_str(&source.read()?);
+        }
+        Ok(text)
+    }
+
+    /// Reads every source as lines.
+    pub fn lines(&self) -> Result<impl Iterator<Item = String>, InputError> {
+        let text = self.read_to_string()?;
+        Ok(text
+            .lines()
+            .map(str::to_string)
+            .collect::<Vec<_>>()
+            .into_iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_args() {
+        let input = Input::from_args(["ex.txt", "--dim", "7", "-", "--input", "more.txt"]).unwrap();
+
+        assert_eq!(
+            input.sources(),
+            [
+                Source::Path("ex.txt".into()),
+                Source::Stdin,
+                Source::Path("more.txt".into())
+            ]
+        );
+        assert_eq!(input.params().get::<usize>("dim").unwrap(), 7);
+        assert!(matches!(
+            Input::from_args(["--dim"]),
+            Err(InputError::MissingValue(_))
+        ));
+    }
+
+    #[test]
+    fn joined_sources() {
+        let input = Input::new().text("1,2").embedded("3,4\n").text("5,6");
+
+        assert_eq!(input.read_to_string().unwrap(), "1,2\n3,4\n5,6");
+        assert!(matches!(
+            Input::new().path("does/not/exist").read_to_string(),
+            Err(InputError::Path { .. })
+        ));
+    }
+
+    #[test]
+    fn typed_params() {
+        let params = Params::new().with("count", "abc");
+
+        assert!(matches!(
+            params.get::<u64>("count"),
+            Err(ParamError::Invalid { .. })
+        ));
+        assert!(matches!(
+            params.get::<u64>("dim"),
+            Err(ParamError::Missing(_))
+        ));
+        assert_eq!(params.get_opt::<u64>("dim").unwrap(), None);
+    }
+}
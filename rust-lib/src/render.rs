@@ -0,0 +1,525 @@
+//! Styled rendering of grids to terminals, plain text and PNG.
+//!
+//! ```
+//! use advent_rust_lib::{
+//!     grid::{Pos2D, RectangleGrid},
+//!     render::{Canvas, Gradient, Style},
+//! };
+//!
+//! let (walls, _) = RectangleGrid::parse_chars("#...\n#.#.\n....", &[], |c| c == '#').unwrap();
+//! let mut canvas = Canvas::from_grid(&walls, |wall| Style::new(if *wall { '#' } else { '.' }));
+//!
+//! let path = [Pos2D::new(1, 0), Pos2D::new(1, 1), Pos2D::new(1, 2), Pos2D::new(2, 2)];
+//! canvas.overlay(path, Some('o'), Gradient::Turbo);
+//!
+//! let mut plain = Vec::new();
+//! canvas.write_plain(&mut plain).unwrap();
+//! assert_eq!(String::from_utf8(plain).unwrap(), "#o..\n#o#.\n.oo.\n");
+//! ```
+
+use std::{io::Write, thread, time::Duration};
+
+use thiserror::Error;
+
+use crate::grid::{Grid, Pos2D, RectangleGrid};
+
+#[cfg(feature = "num")]
+use crate::grid_ext::Pos2DExt;
+
+pub type Rgb = [u8; 3];
+
+#[derive(Debug, Error)]
+pub enum RenderError {
+    #[error("{0}")]
+    Io(#[from] std::io::Error),
+    #[error("{0}")]
+    Png(#[from] png::EncodingError),
+    #[error("Cannot encode an image with no pixels")]
+    Empty,
+    #[error("Image is too large to encode")]
+    TooLarge,
+    #[error("Frame {0} is a different size from the first frame")]
+    FrameSize(usize),
+}
+
+/// How a single cell is drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Style {
+    pub glyph: char,
+    pub fg: Option<Rgb>,
+    pub bg: Option<Rgb>,
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Self::new(' ')
+    }
+}
+
+impl Style {
+    pub const fn new(glyph: char) -> Self {
+        Self {
+            glyph,
+            fg: None,
+            bg: None,
+        }
+    }
+
+    pub const fn fg(mut self, color: Rgb) -> Self {
+        self.fg = Some(color);
+        self
+    }
+
+    pub const fn bg(mut self, color: Rgb) -> Self {
+        self.bg = Some(color);
+        self
+    }
+
+    /// Color of this cell as a pixel.
+    ///
+    /// Uncolored glyphs are white, and uncolored blank cells black.
+    pub fn pixel(&self) -> Rgb {
+        self.bg.or(self.fg).unwrap_or(match self.glyph {
+            ' ' => [0, 0, 0],
+            _ => [255, 255, 255],
+        })
+    }
+}
+
+// ------------------------------------------------------------ //
+
+/// Color scales for overlays.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Gradient {
+    /// The turbo colormap, from dark blue through green to dark red.
+    Turbo,
+    /// Straight line between two colors.
+    Linear(Rgb, Rgb),
+}
+
+impl Gradient {
+    /// Color at `t`, clamped to `0.0..=1.0`.
+    pub fn sample(&self, t: f64) -> Rgb {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            // Polynomial approximation of turbo, which is close enough for a
+            // terminal.
+            Self::Turbo => {
+                const COEFFS: [[f64; 6]; 3] = [
+                    [0.1357, 4.6154, -42.6603, 132.1311, -152.9424, 59.2864],
+                    [0.0914, 2.1942, 4.8430, -14.1850, 4.2773, 2.8296],
+                    [0.1067, 12.6419, -60.5820, 110.3628, -89.9031, 27.3482],
+                ];
+                COEFFS.map(|coeffs| {
+                    let channel = coeffs.iter().rev().fold(0.0, |acc, c| acc * t + c);
+                    (channel.clamp(0.0, 1.0) * 255.0).round() as u8
+                })
+            }
+            Self::Linear(start, end) => std::array::from_fn(|idx| {
+                let (start, end) = (f64::from(start[idx]), f64::from(end[idx]));
+                (start + (end - start) * t).round() as u8
+            }),
+        }
+    }
+
+    /// `count` evenly spaced colors, from the start to the end of the scale.
+    pub fn colors(&self, count: usize) -> impl ExactSizeIterator<Item = Rgb> + '_ {
+        let last = count.saturating_sub(1).max(1) as f64;
+        (0..count).map(move |idx| self.sample(idx as f64 / last))
+    }
+}
+
+// ------------------------------------------------------------ //
+
+/// A grid of [`Style`]s, ready to be written out.
+#[derive(Debug, Clone)]
+pub struct Canvas {
+    cells: RectangleGrid<Style>,
+    spacing: usize,
+}
+
+impl Canvas {
+    /// Blank canvas of `x_max` by `y_max` cells.
+    pub fn new(x_max: usize, y_max: usize) -> Self {
+        Self {
+            cells: RectangleGrid::default_with_dim(x_max, y_max, Style::default()),
+            spacing: 0,
+        }
+    }
+
+    /// Styles every cell of `grid`, sized to fit its furthest position.
+    pub fn from_grid<G, F>(grid: &G, mut style: F) -> Self
+    where
+        G: Grid<Pos = Pos2D>,
+        F: FnMut(&G::Cell) -> Style,
+    {
+        let (x_max, y_max) = grid
+            .positions()
+            .fold((0, 0), |(x, y), pos| (x.max(pos.x + 1), y.max(pos.y + 1)));
+
+        let mut canvas = Self::new(x_max, y_max);
+        for (pos, cell) in grid.cells() {
+            canvas.set(pos, style(cell));
+        }
+        canvas
+    }
+
+    /// Styles every cell of a grid with signed positions, such as a
+    /// [`crate::sparse_grid::SparseGrid`] or
+    /// [`crate::sparse_grid::InfiniteGrid`].
+    ///
+    /// The canvas covers the bounding box of the grid's positions, with its
+    /// top left corner at (0, 0).
+    #[cfg(feature = "num")]
+    pub fn from_sparse<G, F>(grid: &G, mut style: F) -> Self
+    where
+        G: Grid<Pos = Pos2DExt<i64>>,
+        F: FnMut(&G::Cell) -> Style,
+    {
+        let Some(low) = grid
+            .positions()
+            .reduce(|low, pos| Pos2DExt::new(low.x.min(pos.x), low.y.min(pos.y)))
+        else {
+            return Self::new(0, 0);
+        };
+        let high = grid.positions().fold(low, |high, pos| {
+            Pos2DExt::new(high.x.max(pos.x), high.y.max(pos.y))
+        });
+
+        let offset = |value: i64, min: i64| (value - min) as usize;
+        let mut canvas = Self::new(offset(high.x, low.x) + 1, offset(high.y, low.y) + 1);
+        for (pos, cell) in grid.cells() {
+            let pos = Pos2D::new(offset(pos.x, low.x), offset(pos.y, low.y));
+            canvas.set(pos, style(cell));
+        }
+        canvas
+    }
+
+    /// Writes `spacing` blank characters after each cell in text output,
+    /// which helps square up terminal cells.
+    pub fn spaced(mut self, spacing: usize) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    pub fn x_max(&self) -> usize {
+        self.cells.x_max()
+    }
+
+    pub fn y_max(&self) -> usize {
+        self.cells.y_max()
+    }
+
+    pub fn get(&self, pos: Pos2D) -> Option<&Style> {
+        self.cells.get(pos)
+    }
+
+    /// Returns false, changing nothing, if `pos` is outside the canvas.
+    pub fn set(&mut self, pos: Pos2D, style: Style) -> bool {
+        self.cells.get_mut(pos).map(|cell| *cell = style).is_some()
+    }
+
+    /// Colors `path` along `gradient` in order, replacing glyphs with `glyph`
+    /// if given.
+    ///
+    /// Later positions draw over earlier ones, and positions outside the
+    /// canvas are skipped.
+    pub fn overlay<I>(&mut self, path: I, glyph: Option<char>, gradient: Gradient)
+    where
+        I: IntoIterator<Item = Pos2D>,
+    {
+        let path: Vec<_> = path.into_iter().collect();
+        for (pos, color) in path.iter().zip(gradient.colors(path.len())) {
+            if let Some(cell) = self.cells.get_mut(*pos) {
+                cell.glyph = glyph.unwrap_or(cell.glyph);
+                cell.fg = Some(color);
+            }
+        }
+    }
+
+    /// Sets the background of each of `positions`, e.g. to mark the cells
+    /// changed by a step.
+    pub fn highlight<I>(&mut self, positions: I, color: Rgb)
+    where
+        I: IntoIterator<Item = Pos2D>,
+    {
+        for pos in positions {
+            if let Some(cell) = self.cells.get_mut(pos) {
+                cell.bg = Some(color);
+            }
+        }
+    }
+
+    fn write_text<W, F>(&self, sink: &mut W, mut write_cell: F) -> Result<(), std::io::Error>
+    where
+        W: Write,
+        F: FnMut(&mut W, &Style) -> Result<(), std::io::Error>,
+    {
+        for line in self.cells.lines() {
+            for cell in line {
+                write_cell(sink, cell)?;
+                write!(sink, "{:1$}", "", self.spacing)?;
+            }
+            writeln!(sink)?;
+        }
+        Ok(())
+    }
+
+    /// Writes only the glyphs.
+    pub fn write_plain<W: Write>(&self, sink: &mut W) -> Result<(), std::io::Error> {
+        self.write_text(sink, |sink, cell| write!(sink, "{}", cell.glyph))
+    }
+
+    /// Writes the glyphs with 24-bit ANSI color escapes.
+    pub fn write_ansi<W: Write>(&self, sink: &mut W) -> Result<(), std::io::Error> {
+        self.write_text(sink, |sink, cell| {
+            if let Some([r, g, b]) = cell.fg {
+                write!(sink, "\x1b[38;2;{r};{g};{b}m")?;
+            }
+            if let Some([r, g, b]) = cell.bg {
+                write!(sink, "\x1b[48;2;{r};{g};{b}m")?;
+            }
+            write!(sink, "{}", cell.glyph)?;
+            if cell.fg.is_some() || cell.bg.is_some() {
+                write!(sink, "\x1b[0m")?;
+            }
+            Ok(())
+        })
+    }
+
+    /// RGB pixels with each cell drawn as a `scale` pixel square.
+    fn pixels(&self, scale: usize) -> Vec<u8> {
+        self.cells
+            .lines()
+            .flat_map(|line| {
+                let row: Vec<_> = line
+                    .iter()
+                    .flat_map(|cell| std::iter::repeat_n(cell.pixel(), scale))
+                    .flatten()
+                    .collect();
+                std::iter::repeat_n(row, scale).flatten()
+            })
+            .collect()
+    }
+
+    fn png_encoder<W: Write>(
+        &self,
+        sink: W,
+        scale: usize,
+    ) -> Result<png::Encoder<'static, W>, RenderError> {
+        let pixels = |cells: usize| {
+            cells
+                .checked_mul(scale)
+                .and_then(|pixels| u32::try_from(pixels).ok())
+                .ok_or(RenderError::TooLarge)
+        };
+        let (width, height) = (pixels(self.x_max())?, pixels(self.y_max())?);
+        if width == 0 || height == 0 {
+            return Err(RenderError::Empty);
+        }
+
+        let mut encoder = png::Encoder::new(sink, width, height);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        Ok(encoder)
+    }
+
+    /// Writes a PNG image with each cell drawn as a `scale` pixel square in
+    /// the color of [`Style::pixel`].
+    pub fn write_png<W: Write>(&self, sink: W, scale: usize) -> Result<(), RenderError> {
+        let mut writer = self.png_encoder(sink, scale)?.write_header()?;
+        writer.write_image_data(&self.pixels(scale))?;
+        Ok(writer.finish()?)
+    }
+}
+
+// ------------------------------------------------------------ //
+
+/// A sequence of same size canvases, e.g. the steps of a simulation.
+#[derive(Debug, Clone, Default)]
+pub struct Frames {
+    frames: Vec<Canvas>,
+}
+
+impl Frames {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, frame: Canvas) {
+        self.frames.push(frame);
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &Canvas> {
+        self.frames.iter()
+    }
+
+    /// Writes an animated PNG that shows each frame for `delay_ms` and loops
+    /// forever.
+    pub fn write_apng<W: Write>(
+        &self,
+        sink: W,
+        scale: usize,
+        delay_ms: u16,
+    ) -> Result<(), RenderError> {
+        let first = self.frames.first().ok_or(RenderError::Empty)?;
+        if let Some(idx) = self
+            .frames
+            .iter()
+            .position(|frame| (frame.x_max(), frame.y_max()) != (first.x_max(), first.y_max()))
+        {
+            return Err(RenderError::FrameSize(idx));
+        }
+
+        let mut encoder = first.png_encoder(sink, scale)?;
+        encoder.set_animated(self.frames.len() as u32, 0)?;
+        encoder.set_frame_delay(delay_ms, 1000)?;
+
+        let mut writer = encoder.write_header()?;
+        for frame in &self.frames {
+            writer.write_image_data(&frame.pixels(scale))?;
+        }
+        Ok(writer.finish()?)
+    }
+
+    /// Plays the frames in a terminal, clearing it before each frame.
+    pub fn play<W: Write>(&self, sink: &mut W, delay: Duration) -> Result<(), std::io::Error> {
+        for frame in &self.frames {
+            write!(sink, "\x1b[H\x1b[2J")?;
+            frame.write_ansi(sink)?;
+            sink.flush()?;
+            thread::sleep(delay);
+        }
+        Ok(())
+    }
+}
+
+impl FromIterator<Canvas> for Frames {
+    fn from_iter<I: IntoIterator<Item = Canvas>>(iter: I) -> Self {
+        Self {
+            frames: iter.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn canvas(lines: &[&str]) -> Canvas {
+        let grid =
+            RectangleGrid::try_from_iter_strict(lines.iter().map(|line| line.chars())).unwrap();
+        Canvas::from_grid(&grid, |c| Style::new(*c))
+    }
+
+    #[test]
+    fn gradients() {
+        let linear = Gradient::Linear([0, 0, 0], [200, 100, 0]);
+        let colors: Vec<_> = linear.colors(3).collect();
+        assert_eq!(colors, [[0, 0, 0], [100, 50, 0], [200, 100, 0]]);
+        assert_eq!(linear.colors(1).collect::<Vec<_>>(), [[0, 0, 0]]);
+        assert_eq!(linear.sample(2.0), [200, 100, 0]);
+
+        // Dark at both ends, and red at the end
+        let [start, middle, end] = [0.0, 0.5, 1.0].map(|t| Gradient::Turbo.sample(t));
+        let brightness = |color: Rgb| color.iter().map(|c| u32::from(*c)).sum::<u32>();
+        assert!(brightness(start) < brightness(middle) && brightness(end) < brightness(middle));
+        assert!(end[0] > end[2]);
+    }
+
+    #[test]
+    fn text_output() {
+        let mut canvas = canvas(&["ab", "cd"]).spaced(1);
+        canvas.overlay([Pos2D::new(1, 1), Pos2D::new(5, 5)], None, Gradient::Turbo);
+
+        let mut plain = Vec::new();
+        canvas.write_plain(&mut plain).unwrap();
+        assert_eq!(String::from_utf8(plain).unwrap(), "a b \nc d \n");
+
+        let mut ansi = Vec::new();
+        canvas.write_ansi(&mut ansi).unwrap();
+        let [r, g, b] = Gradient::Turbo.sample(0.0);
+        assert_eq!(
+            String::from_utf8(ansi).unwrap(),
+            format!("a b \nc \x1b[38;2;{r};{g};{b}md\x1b[0m \n")
+        );
+    }
+
+    #[cfg(feature = "num")]
+    #[test]
+    fn sparse_output() {
+        use crate::sparse_grid::InfiniteGrid;
+
+        let mut grid = InfiniteGrid::new('.');
+        grid.set(Pos2DExt::new(-2, 3), '#');
+        grid.set(Pos2DExt::new(0, 4), '#');
+        let canvas = Canvas::from_sparse(&grid, |c| Style::new(*c));
+
+        let mut plain = Vec::new();
+        canvas.write_plain(&mut plain).unwrap();
+        assert_eq!(String::from_utf8(plain).unwrap(), "#..\n..#\n");
+
+        let empty = Canvas::from_sparse(&InfiniteGrid::new('.'), |c| Style::new(*c));
+        assert_eq!((empty.x_max(), empty.y_max()), (0, 0));
+    }
+
+    #[test]
+    fn png_output() {
+        let mut canvas = canvas(&["# ", " #"]);
+        canvas.highlight([Pos2D::new(1, 0)], [255, 0, 0]);
+        assert_eq!(
+            canvas.pixels(1),
+            [255, 255, 255, 255, 0, 0, 0, 0, 0, 255, 255, 255]
+        );
+        assert_eq!(canvas.pixels(2).len(), 4 * 4 * 3);
+
+        let mut png = Vec::new();
+        canvas.write_png(&mut png, 2).unwrap();
+        assert!(png.starts_with(b"\x89PNG"));
+
+        assert!(matches!(
+            Canvas::new(0, 0).write_png(Vec::new(), 1),
+            Err(RenderError::Empty)
+        ));
+        assert!(matches!(
+            Canvas::new(2, 1).write_png(Vec::new(), usize::MAX),
+            Err(RenderError::TooLarge)
+        ));
+        assert!(matches!(
+            Canvas::new(1, 1).write_png(Vec::new(), 1 << 32),
+            Err(RenderError::TooLarge)
+        ));
+    }
+
+    #[test]
+    fn animation() {
+        let frames: Frames = [canvas(&["#."]), canvas(&[".#"])].into_iter().collect();
+        let mut apng = Vec::new();
+        frames.write_apng(&mut apng, 1, 100).unwrap();
+        assert!(apng.windows(4).any(|chunk| chunk == b"acTL"));
+
+        let mut played = Vec::new();
+        frames.play(&mut played, Duration::ZERO).unwrap();
+        assert_eq!(
+            String::from_utf8(played)
+                .unwrap()
+                .matches("\x1b[2J")
+                .count(),
+            2
+        );
+
+        let mismatched: Frames = [canvas(&["#"]), canvas(&["##"])].into_iter().collect();
+        assert!(matches!(
+            mismatched.write_apng(Vec::new(), 1, 100),
+            Err(RenderError::FrameSize(1))
+        ));
+    }
+}
@@ -0,0 +1,689 @@
+//! Shortest path searches over arbitrary states.
+//!
+//! ```
+//! use advent_rust_lib::search::dijkstra;
+//!
+//! // Reach 10 by adding 1 (cost 1) or doubling (cost 2)
+//! let result = dijkstra(1_u32, |n: &u32| [(n + 1, 1_u32), (n * 2, 2)], |n| *n == 10);
+//!
+//! assert_eq!(result.goal_cost(), Some(6));
+//! assert_eq!(result.path().unwrap(), [1, 2, 4, 5, 10]);
+//! ```
+
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::{hash_map::Entry, BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// Search configuration.
+///
+/// By default only the first predecessor found for each state is kept, which
+/// is enough to rebuild one optimal path. [`Self::all_predecessors`] keeps
+/// every predecessor on an optimal path and keeps searching until every goal
+/// with the optimal cost is found.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Search {
+    all_predecessors: bool,
+}
+
+impl Search {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn all_predecessors(mut self) -> Self {
+        self.all_predecessors = true;
+        self
+    }
+
+    /// Breadth first search, where every step costs 1.
+    ///
+    /// Searches every reachable state if `is_goal` is never true.
+    pub fn bfs<S, F, I, G>(
+        &self,
+        start: S,
+        mut successors: F,
+        mut is_goal: G,
+    ) -> SearchResult<S, usize>
+    where
+        S: Clone + Eq + Hash,
+        F: FnMut(&S) -> I,
+        I: IntoIterator<Item = S>,
+        G: FnMut(&S) -> bool,
+    {
+        let mut result = SearchResult::new(start.clone());
+        if let Some(node) = result.nodes.get_mut(&start) {
+            node.settled = true;
+        }
+        let mut to_visit = VecDeque::from([(start, 0)]);
+
+        while let Some((state, cost)) = to_visit.pop_front() {
+            if result.goal_cost().is_some_and(|goal_cost| cost > goal_cost) {
+                break;
+            }
+
+            if is_goal(&state) {
+                result.goals.push(state);
+                if self.all_predecessors {
+                    continue;
+                }
+                break;
+            }
+
+            for next in successors(&state) {
+                match result.nodes.entry(next) {
+                    Entry::Occupied(mut entry) => {
+                        let node = entry.get_mut();
+                        if self.all_predecessors && node.cost == cost + 1 {
+                            node.predecessors.push(state.clone());
+                        }
+                    }
+                    Entry::Vacant(entry) => {
+                        to_visit.push_back((entry.key().clone(), cost + 1));
+                        entry.insert(Node {
+                            cost: cost + 1,
+                            predecessors: vec![state.clone()],
+                            settled: true,
+                        });
+                    }
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Lowest cost search with non-negative costs.
+    ///
+    /// Searches every reachable state if `is_goal` is never true.
+    pub fn dijkstra<S, C, F, I, G>(&self, start: S, successors: F, is_goal: G) -> SearchResult<S, C>
+    where
+        S: Clone + Eq + Hash,
+        C: Copy + Ord + Add<Output = C> + Default,
+        F: FnMut(&S) -> I,
+        I: IntoIterator<Item = (S, C)>,
+        G: FnMut(&S) -> bool,
+    {
+        self.astar(start, successors, |_| C::default(), is_goal)
+    }
+
+    /// [`Self::dijkstra`], visiting states in order of cost plus `heuristic`.
+    ///
+    /// The result is only optimal when `heuristic` never overestimates the
+    /// remaining cost and never decreases by more than a step's cost.
+    pub fn astar<S, C, F, I, H, G>(
+        &self,
+        start: S,
+        mut successors: F,
+        mut heuristic: H,
+        mut is_goal: G,
+    ) -> SearchResult<S, C>
+    where
+        S: Clone + Eq + Hash,
+        C: Copy + Ord + Add<Output = C> + Default,
+        F: FnMut(&S) -> I,
+        I: IntoIterator<Item = (S, C)>,
+        H: FnMut(&S) -> C,
+        G: FnMut(&S) -> bool,
+    {
+        let mut result = SearchResult::new(start.clone());
+        let mut to_visit = BinaryHeap::from([Reverse(Queued {
+            priority: heuristic(&start),
+            cost: C::default(),
+            state: start,
+        })]);
+
+        while let Some(Reverse(Queued {
+            priority,
+            cost,
+            state,
+        })) = to_visit.pop()
+        {
+            if result
+                .goal_cost()
+                .is_some_and(|goal_cost| priority > goal_cost)
+            {
+                break;
+            }
+
+            let node = result
+                .nodes
+                .get_mut(&state)
+                .expect("queued states have nodes");
+            if node.settled || node.cost < cost {
+                continue;
+            }
+            node.settled = true;
+
+            if is_goal(&state) {
+                result.goals.push(state);
+                if self.all_predecessors {
+                    continue;
+                }
+                break;
+            }
+
+            for (next, step_cost) in successors(&state) {
+                let next_cost = cost + step_cost;
+
+                match result.nodes.entry(next) {
+                    Entry::Occupied(mut entry) => {
+                        let node = entry.get_mut();
+                        match next_cost.cmp(&node.cost) {
+                            Ordering::Less if !node.settled => {
+                                node.cost = next_cost;
+                                node.predecessors.clear();
+                                node.predecessors.push(state.clone());
+                                to_visit.push(Reverse(Queued {
+                                    priority: next_cost + heuristic(entry.key()),
+                                    cost: next_cost,
+                                    state: entry.key().clone(),
+                                }));
+                            }
+                            Ordering::Equal if self.all_predecessors => {
+                                node.predecessors.push(state.clone());
+                            }
+                            _ => (),
+                        }
+                    }
+                    Entry::Vacant(entry) => {
+                        to_visit.push(Reverse(Queued {
+                            priority: next_cost + heuristic(entry.key()),
+                            cost: next_cost,
+                            state: entry.key().clone(),
+                        }));
+                        entry.insert(Node {
+                            cost: next_cost,
+                            predecessors: vec![state.clone()],
+                            settled: false,
+                        });
+                    }
+                }
+            }
+        }
+
+        result
+    }
+}
+
+/// [`Search::bfs`] with the default configuration.
+pub fn bfs<S, F, I, G>(start: S, successors: F, is_goal: G) -> SearchResult<S, usize>
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+    G: FnMut(&S) -> bool,
+{
+    Search::new().bfs(start, successors, is_goal)
+}
+
+/// [`Search::dijkstra`] with the default configuration.
+pub fn dijkstra<S, C, F, I, G>(start: S, successors: F, is_goal: G) -> SearchResult<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    F: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+    G: FnMut(&S) -> bool,
+{
+    Search::new().dijkstra(start, successors, is_goal)
+}
+
+/// [`Search::astar`] with the default configuration.
+pub fn astar<S, C, F, I, H, G>(
+    start: S,
+    successors: F,
+    heuristic: H,
+    is_goal: G,
+) -> SearchResult<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    F: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+    H: FnMut(&S) -> C,
+    G: FnMut(&S) -> bool,
+{
+    Search::new().astar(start, successors, heuristic, is_goal)
+}
+
+// -------------------------------------------------- //
+
+/// Ordered by priority only, so states do not need to be [`Ord`].
+#[derive(Debug)]
+struct Queued<S, C> {
+    priority: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: PartialEq> PartialEq for Queued<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S, C: Eq> Eq for Queued<S, C> {}
+
+impl<S, C: Ord> PartialOrd for Queued<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> Ord for Queued<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.priority.cmp(&other.priority)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Node<S, C> {
+    cost: C,
+    predecessors: Vec<S>,
+    settled: bool,
+}
+
+/// Costs and predecessors found by a search.
+#[derive(Debug, Clone)]
+pub struct SearchResult<S, C> {
+    start: S,
+    nodes: HashMap<S, Node<S, C>>,
+    goals: Vec<S>,
+}
+
+impl<S, C> SearchResult<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Default,
+{
+    fn new(start: S) -> Self {
+        let nodes = HashMap::from([(
+            start.clone(),
+            Node {
+                cost: C::default(),
+                predecessors: Vec::new(),
+                settled: false,
+            },
+        )]);
+
+        Self {
+            start,
+            nodes,
+            goals: Vec::new(),
+        }
+    }
+
+    pub fn start(&self) -> &S {
+        &self.start
+    }
+
+    /// First goal reached.
+    pub fn goal(&self) -> Option<&S> {
+        self.goals.first()
+    }
+
+    /// Every goal reached at the optimal cost, when searching with
+    /// [`Search::all_predecessors`].
+    pub fn goals(&self) -> &[S] {
+        &self.goals
+    }
+
+    pub fn goal_cost(&self) -> Option<C> {
+        self.cost(self.goal()?)
+    }
+
+    /// Optimal cost to reach `state`, if it was reached before the search
+    /// stopped.
+    pub fn cost(&self, state: &S) -> Option<C> {
+        self.nodes
+            .get(state)
+            .filter(|node| node.settled)
+            .map(|node| node.cost)
+    }
+
+    /// Every state with a known optimal cost.
+    pub fn costs(&self) -> impl Iterator<Item = (&S, C)> {
+        self.nodes
+            .iter()
+            .filter(|(_, node)| node.settled)
+            .map(|(state, node)| (state, node.cost))
+    }
+
+    /// States directly before `state` on an optimal path to it.
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.nodes
+            .get(state)
+            .filter(|node| node.settled)
+            .map(|node| node.predecessors.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// One optimal path from the start to `state`, inclusive.
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        self.cost(state)?;
+
+        let mut path = vec![state.clone()];
+        while *path.last()? != self.start {
+            path.push(self.predecessors(path.last()?).first()?.clone());
+        }
+
+        path.reverse();
+        Some(path)
+    }
+
+    /// One optimal path from the start to the first goal, inclusive.
+    pub fn path(&self) -> Option<Vec<S>> {
+        self.path_to(self.goal()?)
+    }
+
+    /// Every optimal path to [`Self::goals`].
+    ///
+    /// Only includes more than one path per goal when searching with
+    /// [`Search::all_predecessors`].
+    pub fn dag(&self) -> PredecessorDag<'_, S, C> {
+        PredecessorDag {
+            result: self,
+            targets: self.goals.clone(),
+        }
+    }
+
+    /// Every optimal path to any of `targets`.
+    pub fn dag_to<T: IntoIterator<Item = S>>(&self, targets: T) -> PredecessorDag<'_, S, C> {
+        PredecessorDag {
+            result: self,
+            targets: targets.into_iter().collect(),
+        }
+    }
+}
+
+// -------------------------------------------------- //
+
+/// Counter for [`PredecessorDag::count_paths`].
+pub trait PathCount: Sized {
+    fn zero() -> Self;
+    fn one() -> Self;
+    /// None on overflow.
+    fn checked_add(&self, rhs: &Self) -> Option<Self>;
+}
+
+macro_rules! impl_path_count {
+    ($($ty:ty),*) => {
+        $(
+            impl PathCount for $ty {
+                fn zero() -> Self {
+                    0
+                }
+
+                fn one() -> Self {
+                    1
+                }
+
+                fn checked_add(&self, rhs: &Self) -> Option<Self> {
+                    <$ty>::checked_add(*self, *rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_path_count!(u8, u16, u32, u64, u128, usize);
+
+#[cfg(feature = "num")]
+impl PathCount for num::BigUint {
+    fn zero() -> Self {
+        num::Zero::zero()
+    }
+
+    fn one() -> Self {
+        num::One::one()
+    }
+
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        Some(self + rhs)
+    }
+}
+
+/// Predecessor graph of every optimal path from the search start to a set of
+/// targets.
+///
+/// Step costs must be positive, so that the graph has no cycles.
+///
+/// ```
+/// use advent_rust_lib::search::Search;
+///
+/// // Reach 3 by adding 1 or 2 at each step
+/// let result = Search::new()
+///     .all_predecessors()
+///     .dijkstra(0, |n: &u8| [(n + 1, 1_u8), (n + 2, 1)], |n| *n >= 3);
+/// let dag = result.dag();
+///
+/// // 0 -> 1 -> 3, 0 -> 2 -> 3, 0 -> 2 -> 4
+/// assert_eq!(dag.count_paths::<u64>(), Some(3));
+/// assert_eq!(dag.nodes().len(), 5);
+/// assert!(dag.paths().any(|path| path == [0, 2, 4]));
+/// ```
+#[derive(Debug, Clone)]
+pub struct PredecessorDag<'a, S, C> {
+    result: &'a SearchResult<S, C>,
+    targets: Vec<S>,
+}
+
+impl<'a, S, C> PredecessorDag<'a, S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Default,
+{
+    pub fn targets(&self) -> &[S] {
+        &self.targets
+    }
+
+    /// Union of the states on any optimal path, including the start and
+    /// reached targets.
+    pub fn nodes(&self) -> HashSet<S> {
+        let mut nodes = HashSet::new();
+        let mut to_visit: Vec<_> = self
+            .targets
+            .iter()
+            .filter(|target| self.result.cost(target).is_some())
+            .collect();
+
+        while let Some(state) = to_visit.pop() {
+            if nodes.insert(state.clone()) && *state != self.result.start {
+                to_visit.extend(self.result.predecessors(state));
+            }
+        }
+
+        nodes
+    }
+
+    /// Number of distinct optimal paths, summed over every target.
+    ///
+    /// Returns None if the count overflows `N`. `num::BigUint` never
+    /// overflows, with the `num` feature.
+    pub fn count_paths<N: PathCount>(&self) -> Option<N> {
+        let start = &self.result.start;
+        let mut counts: HashMap<&S, N> = HashMap::new();
+        // (state, predecessors already counted)
+        let mut to_visit: Vec<_> = self.targets.iter().map(|target| (target, false)).collect();
+
+        while let Some((state, expanded)) = to_visit.pop() {
+            if counts.contains_key(state) {
+                continue;
+            }
+
+            if state == start {
+                counts.insert(state, N::one());
+            } else if expanded {
+                let mut count = N::zero();
+                for prev in self.result.predecessors(state) {
+                    count = count.checked_add(&counts[prev])?;
+                }
+                counts.insert(state, count);
+            } else {
+                to_visit.push((state, true));
+                to_visit.extend(
+                    self.result
+                        .predecessors(state)
+                        .iter()
+                        .filter(|prev| !counts.contains_key(prev))
+                        .map(|prev| (prev, false)),
+                );
+            }
+        }
+
+        self.targets.iter().try_fold(N::zero(), |total, target| {
+            total.checked_add(&counts[target])
+        })
+    }
+
+    /// Lazily produces each optimal path, from the start to a target
+    /// inclusive.
+    pub fn paths(&self) -> Paths<'_, S, C> {
+        Paths {
+            result: self.result,
+            to_visit: self
+                .targets
+                .iter()
+                .filter(|target| self.result.cost(target).is_some())
+                .map(|target| vec![target])
+                .collect(),
+        }
+    }
+}
+
+/// Iterator from [`PredecessorDag::paths`].
+#[derive(Debug, Clone)]
+pub struct Paths<'a, S, C> {
+    result: &'a SearchResult<S, C>,
+    // Partial paths, from a target back towards the start
+    to_visit: Vec<Vec<&'a S>>,
+}
+
+impl<S, C> Iterator for Paths<'_, S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Default,
+{
+    type Item = Vec<S>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(partial) = self.to_visit.pop() {
+            let last = *partial.last().expect("partial paths are never empty");
+
+            if *last == self.result.start {
+                return Some(partial.into_iter().rev().cloned().collect());
+            }
+
+            for prev in self.result.predecessors(last).iter().rev() {
+                let mut extended = partial.clone();
+                extended.push(prev);
+                self.to_visit.push(extended);
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Open 3x3 grid, moving right or down.
+    fn right_down(&(x, y): &(u8, u8)) -> Vec<(u8, u8)> {
+        [(x + 1, y), (x, y + 1)]
+            .into_iter()
+            .filter(|(x, y)| *x < 3 && *y < 3)
+            .collect()
+    }
+
+    #[test]
+    fn bfs_distances() {
+        let result = bfs((0, 0), right_down, |_| false);
+
+        assert_eq!(result.cost(&(2, 2)), Some(4));
+        assert_eq!(result.costs().count(), 9);
+        assert_eq!(result.goal(), None);
+        assert_eq!(result.path_to(&(0, 2)).unwrap(), [(0, 0), (0, 1), (0, 2)]);
+    }
+
+    #[test]
+    fn all_predecessors() {
+        let search = Search::new().all_predecessors();
+
+        let result = search.bfs((0, 0), right_down, |pos| *pos == (1, 1));
+        assert_eq!(result.predecessors(&(1, 1)), [(1, 0), (0, 1)]);
+
+        let result = search.dijkstra(
+            (0, 0),
+            |pos| right_down(pos).into_iter().map(|next| (next, 1)),
+            |pos| pos.0 + pos.1 == 2,
+        );
+        assert_eq!(result.goals().len(), 3);
+        assert_eq!(result.predecessors(&(1, 1)).len(), 2);
+        assert_eq!(
+            Search::new()
+                .bfs((0, 0), right_down, |_| false)
+                .predecessors(&(1, 1))
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn optimal_path_counts() {
+        // Moving right or down in a `dim` square
+        let grid_result = |dim: u8| {
+            Search::new().all_predecessors().bfs(
+                (0, 0),
+                move |&(x, y): &(u8, u8)| {
+                    [(x + 1, y), (x, y + 1)]
+                        .into_iter()
+                        .filter(move |(x, y)| *x < dim && *y < dim)
+                },
+                move |pos| *pos == (dim - 1, dim - 1),
+            )
+        };
+
+        let result = grid_result(3);
+        let dag = result.dag();
+        assert_eq!(dag.count_paths::<u8>(), Some(6));
+        assert_eq!(dag.paths().count(), 6);
+        assert_eq!(dag.nodes().len(), 9);
+        assert!(dag.paths().all(|path| path.len() == 5));
+
+        // 924 paths
+        let result = grid_result(7);
+        assert_eq!(result.dag().count_paths::<u8>(), None);
+        assert_eq!(result.dag().count_paths::<u16>(), Some(924));
+        assert_eq!(result.dag_to([(1, 6)]).count_paths::<u16>(), Some(7));
+    }
+
+    #[test]
+    fn astar_matches_dijkstra() {
+        let successors = |&(x, y): &(i32, i32)| {
+            [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+                .into_iter()
+                // Wall along x = 2, except at y = 5
+                .filter(|&(x, y)| x != 2 || y == 5)
+                .filter(|&(x, y)| (-10..10).contains(&x) && (-10..10).contains(&y))
+                .map(|next| (next, 1))
+        };
+        let goal = (4, 0);
+
+        let dijkstra = dijkstra((0, 0), successors, |pos| *pos == goal);
+        let astar = astar(
+            (0, 0),
+            successors,
+            |&(x, y)| x.abs_diff(goal.0) + y.abs_diff(goal.1),
+            |pos| *pos == goal,
+        );
+
+        assert_eq!(dijkstra.goal_cost(), Some(14));
+        assert_eq!(astar.goal_cost(), Some(14));
+        assert!(astar.costs().count() < dijkstra.costs().count());
+    }
+}
@@ -0,0 +1,170 @@
+use std::{env::args, error::Error, fmt::Display, process::exit, str::FromStr};
+
+use thiserror::Error;
+
+use crate::read::{Input, InputError};
+pub use crate::read::{ParamError, Params};
+
+/// Error produced while solving one part of a day.
+pub type SolutionError = Box<dyn Error + Send + Sync>;
+
+/// The formatted answer of one part of a day.
+pub type SolutionResult = Result<String, SolutionError>;
+
+/// A single day's puzzle, solvable from its full text input.
+pub trait Solution {
+    fn year(&self) -> u16;
+
+    fn day(&self) -> u8;
+
+    fn part_1(&self, input: &str, params: &Params) -> SolutionResult;
+
+    fn part_2(&self, input: &str, params: &Params) -> SolutionResult;
+
+    /// Solve `part` of this day.
+    fn solve(&self, part: Part, input: &str, params: &Params) -> SolutionResult {
+        match part {
+            Part::One => self.part_1(input, params),
+            Part::Two => self.part_2(input, params),
+        }
+    }
+}
+
+// -------------------------------------------------- //
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn all() -> [Self; 2] {
+        [Self::One, Self::Two]
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::One => write!(f, "Part 1"),
+            Self::Two => write!(f, "Part 2"),
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+#[error("Part must be 1 or 2, not \"{0}\"")]
+pub struct InvalidPart(String);
+
+impl FromStr for Part {
+    type Err = InvalidPart;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "1" => Ok(Self::One),
+            "2" => Ok(Self::Two),
+            x => Err(InvalidPart(x.to_string())),
+        }
+    }
+}
+
+// -------------------------------------------------- //
+
+/// Command line options shared by every solution binary.
+///
+/// `--part` selects a single part, everything else is parsed as an [`Input`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RunArgs {
+    pub input: Input,
+    pub part: Option<Part>,
+}
+
+#[derive(Debug, Error)]
+pub enum RunArgsError {
+    #[error("{0}")]
+    Input(#[from] InputError),
+    #[error("{0}")]
+    InvalidPart(#[from] InvalidPart),
+}
+
+impl RunArgs {
+    pub fn parse<I, S>(args: I) -> Result<Self, RunArgsError>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut input = Input::from_args(args)?;
+        let part = input
+            .params_mut()
+            .remove("part")
+            .map(|part| part.parse())
+            .transpose()?;
+
+        Ok(Self { input, part })
+    }
+
+    /// The parts to run, in order.
+    pub fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(part) => vec![part],
+            None => Part::all().to_vec(),
+        }
+    }
+}
+
+/// Solves and prints the requested parts of `solution`.
+pub fn run(solution: &dyn Solution, args: &RunArgs) -> Result<(), SolutionError> {
+    let input = args.input.read_to_string()?;
+
+    for part in args.parts() {
+        let answer = solution.solve(part, &input, args.input.params())?;
+        println!("{part}: {answer}");
+    }
+
+    Ok(())
+}
+
+/// Entry point for a day's own binary.
+pub fn run_main(solution: &dyn Solution) {
+    let res = RunArgs::parse(args().skip(1))
+        .map_err(SolutionError::from)
+        .and_then(|args| run(solution, &args));
+
+    if let Err(e) = res {
+        eprintln!("{} day {}: {e}", solution.year(), solution.day());
+        exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_run_args() {
+        let args =
+            RunArgs::parse(["ex.txt", "--dim", "7", "--part", "2", "--input", "more.txt"]).unwrap();
+
+        assert_eq!(
+            args.input,
+            Input::new()
+                .path("ex.txt")
+                .path("more.txt")
+                .param("dim", "7")
+        );
+        assert_eq!(args.part, Some(Part::Two));
+    }
+
+    #[test]
+    fn missing_values() {
+        assert!(matches!(
+            RunArgs::parse(["--dim"]),
+            Err(RunArgsError::Input(_))
+        ));
+        assert!(matches!(
+            RunArgs::parse(["--part", "3"]),
+            Err(RunArgsError::InvalidPart(_))
+        ));
+    }
+}
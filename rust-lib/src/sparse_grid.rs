@@ -0,0 +1,432 @@
+//! Grids without fixed bounds, on signed positions.
+//!
+//! ```
+//! use advent_rust_lib::{grid_ext::Pos2DExt, sparse_grid::SparseGrid};
+//!
+//! let mut robots = SparseGrid::new();
+//! for pos in [(3, -1), (0, 0), (3, -1)] {
+//!     *robots.entry(pos.into()).or_insert(0) += 1;
+//! }
+//!
+//! assert_eq!(robots.get(Pos2DExt::new(3, -1)), Some(&2));
+//! assert_eq!(robots.bounds(), Some((Pos2DExt::new(0, -1), Pos2DExt::new(3, 0))));
+//!
+//! let mut printed = Vec::new();
+//! robots
+//!     .print(&mut printed, |count| if count.is_some() { "#" } else { "." })
+//!     .unwrap();
+//! assert_eq!(String::from_utf8(printed).unwrap(), "...#\n#...\n");
+//! ```
+
+use std::{
+    cmp::{max, min},
+    collections::{hash_map::Entry, HashMap},
+    io::Write,
+};
+
+use crate::{
+    grid::{Grid, RectangleGrid},
+    grid_ext::Pos2DExt,
+};
+
+/// Inclusive top left and bottom right corners around `positions`.
+fn bounding_box<I>(positions: I) -> Option<(Pos2DExt<i64>, Pos2DExt<i64>)>
+where
+    I: IntoIterator<Item = Pos2DExt<i64>>,
+{
+    positions.into_iter().fold(None, |bounds, pos| {
+        Some(match bounds {
+            None => (pos, pos),
+            Some((low, high)) => (
+                Pos2DExt::new(min(low.x, pos.x), min(low.y, pos.y)),
+                Pos2DExt::new(max(high.x, pos.x), max(high.y, pos.y)),
+            ),
+        })
+    })
+}
+
+/// Every position between the inclusive corners, in reading order.
+fn box_positions(
+    bounds: Option<(Pos2DExt<i64>, Pos2DExt<i64>)>,
+) -> impl Iterator<Item = Pos2DExt<i64>> {
+    bounds.into_iter().flat_map(|(low, high)| {
+        (low.y..=high.y).flat_map(move |y| (low.x..=high.x).map(move |x| Pos2DExt::new(x, y)))
+    })
+}
+
+// -------------------------------------------------- //
+
+/// Cells stored only at the positions that hold one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Pos2DExt<i64>, T>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self {
+            cells: HashMap::new(),
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of cells.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, pos: Pos2DExt<i64>) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    pub fn get(&self, pos: Pos2DExt<i64>) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    pub fn get_mut(&mut self, pos: Pos2DExt<i64>) -> Option<&mut T> {
+        self.cells.get_mut(&pos)
+    }
+
+    /// Returns the cell previously at `pos`.
+    pub fn insert(&mut self, pos: Pos2DExt<i64>, value: T) -> Option<T> {
+        self.cells.insert(pos, value)
+    }
+
+    pub fn remove(&mut self, pos: Pos2DExt<i64>) -> Option<T> {
+        self.cells.remove(&pos)
+    }
+
+    pub fn entry(&mut self, pos: Pos2DExt<i64>) -> Entry<'_, Pos2DExt<i64>, T> {
+        self.cells.entry(pos)
+    }
+
+    /// Every cell with its position, in no particular order.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (Pos2DExt<i64>, &T)> {
+        self.cells.iter().map(|(pos, value)| (*pos, value))
+    }
+
+    /// Inclusive top left and bottom right corners around every cell.
+    pub fn bounds(&self) -> Option<(Pos2DExt<i64>, Pos2DExt<i64>)> {
+        bounding_box(self.cells.keys().copied())
+    }
+
+    /// Formats every position in [`Self::bounds`] with some function `to_str`
+    /// into `sink`.
+    pub fn print<W, F>(&self, sink: &mut W, mut to_str: F) -> Result<(), std::io::Error>
+    where
+        W: Write,
+        F: FnMut(Option<&T>) -> &str,
+    {
+        if let Some((low, high)) = self.bounds() {
+            for y in low.y..=high.y {
+                for x in low.x..=high.x {
+                    write!(sink, "{}", (to_str)(self.get(Pos2DExt::new(x, y))))?;
+                }
+                writeln!(sink)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> FromIterator<(Pos2DExt<i64>, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Pos2DExt<i64>, T)>>(iter: I) -> Self {
+        Self {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T> Grid for SparseGrid<T> {
+    type Cell = T;
+    type Pos = Pos2DExt<i64>;
+
+    /// Only positions holding a cell are in a sparse grid.
+    fn in_grid(&self, pos: Pos2DExt<i64>) -> bool {
+        self.contains(pos)
+    }
+
+    fn get(&self, pos: Pos2DExt<i64>) -> Option<&T> {
+        SparseGrid::get(self, pos)
+    }
+
+    fn positions(&self) -> impl Iterator<Item = Pos2DExt<i64>> + '_ {
+        self.cells.keys().copied()
+    }
+}
+
+// -------------------------------------------------- //
+
+/// Dense grid that grows to fit any position written to.
+///
+/// Positions that were never written hold the default cell.
+///
+/// ```
+/// use advent_rust_lib::{grid_ext::Pos2DExt, sparse_grid::InfiniteGrid};
+///
+/// let mut lit = InfiniteGrid::new(false);
+/// lit.set(Pos2DExt::new(-2, 5), true);
+/// lit.set(Pos2DExt::new(1, 3), true);
+///
+/// assert!(*lit.get(Pos2DExt::new(-2, 5)));
+/// assert!(!*lit.get(Pos2DExt::new(100, 100)));
+/// assert_eq!(lit.bounds(), Some((Pos2DExt::new(-2, 3), Pos2DExt::new(1, 5))));
+/// assert_eq!(lit.to_rectangle_grid().x_max(), 4);
+/// ```
+#[derive(Debug, Clone)]
+pub struct InfiniteGrid<T> {
+    default: T,
+    // Position of the first cell in `inner`
+    origin: Pos2DExt<i64>,
+    x_len: usize,
+    y_len: usize,
+    inner: Vec<T>,
+    // Inclusive corners of every position written to, which may be smaller
+    // than the allocated area
+    bounds: Option<(Pos2DExt<i64>, Pos2DExt<i64>)>,
+}
+
+impl<T: Clone> InfiniteGrid<T> {
+    /// Empty grid, where every position holds `default`.
+    pub fn new(default: T) -> Self {
+        Self {
+            default,
+            origin: Pos2DExt::new(0, 0),
+            x_len: 0,
+            y_len: 0,
+            inner: Vec::new(),
+            bounds: None,
+        }
+    }
+
+    /// Index into `inner`, if allocated.
+    fn flat_pos(&self, pos: Pos2DExt<i64>) -> Option<usize> {
+        let x = usize::try_from(pos.x - self.origin.x).ok()?;
+        let y = usize::try_from(pos.y - self.origin.y).ok()?;
+        (x < self.x_len && y < self.y_len).then_some((y * self.x_len) + x)
+    }
+
+    /// Reallocates to fit `pos`, at least doubling along each axis that grows.
+    fn grow_to(&mut self, pos: Pos2DExt<i64>) {
+        let (low, high) = if self.inner.is_empty() {
+            (pos, pos)
+        } else {
+            let (x_len, y_len) = (self.x_len as i64, self.y_len as i64);
+            let old_high = Pos2DExt::new(self.origin.x + x_len - 1, self.origin.y + y_len - 1);
+
+            let grow_low = |old: i64, new: i64, len: i64| {
+                if new < old {
+                    min(new, old - len)
+                } else {
+                    old
+                }
+            };
+            let grow_high = |old: i64, new: i64, len: i64| {
+                if new > old {
+                    max(new, old + len)
+                } else {
+                    old
+                }
+            };
+
+            (
+                Pos2DExt::new(
+                    grow_low(self.origin.x, pos.x, x_len),
+                    grow_low(self.origin.y, pos.y, y_len),
+                ),
+                Pos2DExt::new(
+                    grow_high(old_high.x, pos.x, x_len),
+                    grow_high(old_high.y, pos.y, y_len),
+                ),
+            )
+        };
+
+        let x_len = (high.x - low.x + 1) as usize;
+        let y_len = (high.y - low.y + 1) as usize;
+        let mut inner = vec![self.default.clone(); x_len * y_len];
+
+        for (idx, value) in std::mem::take(&mut self.inner).into_iter().enumerate() {
+            let x = self.origin.x + (idx % self.x_len) as i64 - low.x;
+            let y = self.origin.y + (idx / self.x_len) as i64 - low.y;
+            inner[(y as usize * x_len) + x as usize] = value;
+        }
+
+        self.origin = low;
+        self.x_len = x_len;
+        self.y_len = y_len;
+        self.inner = inner;
+    }
+
+    pub fn get(&self, pos: Pos2DExt<i64>) -> &T {
+        match self.flat_pos(pos) {
+            Some(idx) => &self.inner[idx],
+            None => &self.default,
+        }
+    }
+
+    /// Mutable cell at `pos`, growing the grid to fit it.
+    pub fn get_mut(&mut self, pos: Pos2DExt<i64>) -> &mut T {
+        let idx = match self.flat_pos(pos) {
+            Some(idx) => idx,
+            None => {
+                self.grow_to(pos);
+                self.flat_pos(pos).expect("grown to fit")
+            }
+        };
+
+        self.bounds = bounding_box(
+            self.bounds
+                .into_iter()
+                .flat_map(|(low, high)| [low, high])
+                .chain([pos]),
+        );
+        &mut self.inner[idx]
+    }
+
+    pub fn set(&mut self, pos: Pos2DExt<i64>, value: T) {
+        *self.get_mut(pos) = value;
+    }
+
+    /// Inclusive top left and bottom right corners around every position
+    /// written to.
+    pub fn bounds(&self) -> Option<(Pos2DExt<i64>, Pos2DExt<i64>)> {
+        self.bounds
+    }
+
+    /// Every position in [`Self::bounds`], in reading order.
+    pub fn positions(&self) -> impl Iterator<Item = Pos2DExt<i64>> {
+        box_positions(self.bounds)
+    }
+
+    /// Copies [`Self::bounds`] into a fixed grid, with the top left corner at
+    /// (0, 0).
+    pub fn to_rectangle_grid(&self) -> RectangleGrid<T> {
+        let rows = self.bounds.into_iter().flat_map(|(low, high)| {
+            (low.y..=high.y)
+                .map(move |y| (low.x..=high.x).map(move |x| self.get(Pos2DExt::new(x, y)).clone()))
+        });
+
+        RectangleGrid::try_from_iter(rows).expect("every row has the same width")
+    }
+
+    /// Formats every position in [`Self::bounds`] with some function `to_str`
+    /// into `sink`.
+    pub fn print<W, F>(&self, sink: &mut W, mut to_str: F) -> Result<(), std::io::Error>
+    where
+        W: Write,
+        F: FnMut(&T) -> &str,
+    {
+        if let Some((low, high)) = self.bounds {
+            for y in low.y..=high.y {
+                for x in low.x..=high.x {
+                    write!(sink, "{}", (to_str)(self.get(Pos2DExt::new(x, y))))?;
+                }
+                writeln!(sink)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: Clone> Grid for InfiniteGrid<T> {
+    type Cell = T;
+    type Pos = Pos2DExt<i64>;
+
+    /// Only positions in [`InfiniteGrid::bounds`], so that flood fills and
+    /// regions stop at the area written to.
+    fn in_grid(&self, pos: Pos2DExt<i64>) -> bool {
+        self.bounds.is_some_and(|(low, high)| {
+            (low.x..=high.x).contains(&pos.x) && (low.y..=high.y).contains(&pos.y)
+        })
+    }
+
+    fn get(&self, pos: Pos2DExt<i64>) -> Option<&T> {
+        Some(InfiniteGrid::get(self, pos))
+    }
+
+    /// Every position in [`InfiniteGrid::bounds`].
+    fn positions(&self) -> impl Iterator<Item = Pos2DExt<i64>> + '_ {
+        InfiniteGrid::positions(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sparse_cells() {
+        let mut grid: SparseGrid<char> = [((0, 0).into(), 'a'), ((-3, 2).into(), 'b')]
+            .into_iter()
+            .collect();
+        assert_eq!(grid.insert(Pos2DExt::new(0, 0), 'c'), Some('a'));
+        assert!(Grid::in_grid(&grid, Pos2DExt::new(-3, 2)));
+        assert!(!Grid::in_grid(&grid, Pos2DExt::new(-3, 1)));
+
+        assert_eq!(grid.remove(Pos2DExt::new(-3, 2)), Some('b'));
+        assert_eq!(
+            grid.bounds(),
+            Some((Pos2DExt::new(0, 0), Pos2DExt::new(0, 0)))
+        );
+
+        grid.remove(Pos2DExt::new(0, 0));
+        assert_eq!(grid.bounds(), None);
+    }
+
+    #[test]
+    fn infinite_growth() {
+        let mut grid = InfiniteGrid::new(0);
+        let written = [(0, 0), (5, -1), (-7, 3), (2, 20), (-1, -1)];
+        for (idx, pos) in written.into_iter().enumerate() {
+            grid.set(pos.into(), idx + 1);
+        }
+
+        for (idx, pos) in written.into_iter().enumerate() {
+            assert_eq!(*grid.get(pos.into()), idx + 1);
+        }
+        assert_eq!(
+            grid.bounds(),
+            Some((Pos2DExt::new(-7, -1), Pos2DExt::new(5, 20)))
+        );
+        assert_eq!(grid.positions().count(), 13 * 22);
+        assert_eq!(
+            grid.cells().map(|(_, value)| *value).sum::<usize>(),
+            (1..=5).sum()
+        );
+
+        let fixed = grid.to_rectangle_grid();
+        assert_eq!((fixed.x_max(), fixed.y_max()), (13, 22));
+        assert_eq!(fixed.at(crate::grid::Pos2D::new(7, 1)), Some(1));
+    }
+
+    #[test]
+    fn infinite_regions() {
+        let mut grid = InfiniteGrid::new(0);
+        grid.set(Pos2DExt::new(0, 0), 1);
+        grid.set(Pos2DExt::new(2, 1), 1);
+
+        // Both ones, and the zeros between them
+        assert_eq!(grid.label_regions(|lhs, rhs| lhs == rhs).len(), 3);
+        assert_eq!(
+            grid.flood_fill(Pos2DExt::new(1, 0), |_, cell| *cell == 0)
+                .len(),
+            4
+        );
+        assert!(!Grid::in_grid(&grid, Pos2DExt::new(3, 1)));
+        // Nothing has been set, so even the origin is outside the bounds
+        assert!(InfiniteGrid::new(0)
+            .flood_fill(Pos2DExt::new(0, 0), |_, _| true)
+            .is_empty());
+    }
+}
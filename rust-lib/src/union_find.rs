@@ -0,0 +1,290 @@
+//! Disjoint sets with path compression and union by size.
+//!
+//! ```
+//! use advent_rust_lib::union_find::UnionFind;
+//!
+//! let mut circuits: UnionFind<&str> = ["a", "b", "c", "d"].into_iter().collect();
+//! circuits.union("a", "b");
+//! circuits.union("b", "c");
+//!
+//! assert!(circuits.connected(&"a", &"c"));
+//! assert_eq!(circuits.component_count(), 2);
+//! assert_eq!(circuits.size(&"a"), Some(3));
+//! ```
+
+use std::{collections::HashMap, hash::Hash};
+
+/// Disjoint sets over the dense indices `0..len`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IndexUnionFind {
+    parents: Vec<usize>,
+    // Only accurate for roots
+    sizes: Vec<usize>,
+    count: usize,
+}
+
+impl IndexUnionFind {
+    /// `len` sets, each holding only its own index.
+    pub fn new(len: usize) -> Self {
+        Self {
+            parents: (0..len).collect(),
+            sizes: vec![1; len],
+            count: len,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// Adds a new set, returning its index.
+    pub fn push(&mut self) -> usize {
+        let idx = self.len();
+        self.parents.push(idx);
+        self.sizes.push(1);
+        self.count += 1;
+        idx
+    }
+
+    /// Root without compressing the path, for shared access.
+    fn root(&self, mut idx: usize) -> usize {
+        while self.parents[idx] != idx {
+            idx = self.parents[idx];
+        }
+        idx
+    }
+
+    /// Representative index of the set containing `idx`.
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub fn find(&mut self, idx: usize) -> usize {
+        let root = self.root(idx);
+
+        let mut idx = idx;
+        while idx != root {
+            idx = std::mem::replace(&mut self.parents[idx], root);
+        }
+
+        root
+    }
+
+    /// Merges the sets containing `lhs` and `rhs`.
+    ///
+    /// Returns false if they were already in the same set.
+    pub fn union(&mut self, lhs: usize, rhs: usize) -> bool {
+        let lhs = self.find(lhs);
+        let rhs = self.find(rhs);
+        if lhs == rhs {
+            return false;
+        }
+
+        let (small, large) = if self.sizes[lhs] < self.sizes[rhs] {
+            (lhs, rhs)
+        } else {
+            (rhs, lhs)
+        };
+        self.parents[small] = large;
+        self.sizes[large] += self.sizes[small];
+        self.count -= 1;
+
+        true
+    }
+
+    pub fn connected(&mut self, lhs: usize, rhs: usize) -> bool {
+        self.find(lhs) == self.find(rhs)
+    }
+
+    /// Number of indices in the set containing `idx`.
+    pub fn size(&mut self, idx: usize) -> usize {
+        let root = self.find(idx);
+        self.sizes[root]
+    }
+
+    /// Number of disjoint sets.
+    pub fn component_count(&self) -> usize {
+        self.count
+    }
+
+    /// Size of every set, in order of their representatives.
+    pub fn component_sizes(&self) -> impl Iterator<Item = usize> + '_ {
+        self.parents
+            .iter()
+            .enumerate()
+            .filter(|(idx, parent)| *idx == **parent)
+            .map(|(idx, _)| self.sizes[idx])
+    }
+
+    /// Indices in every set, in order of their representatives.
+    pub fn components(&self) -> impl Iterator<Item = Vec<usize>> {
+        let mut components = vec![Vec::new(); self.len()];
+        for idx in 0..self.len() {
+            components[self.root(idx)].push(idx);
+        }
+
+        components
+            .into_iter()
+            .filter(|component| !component.is_empty())
+    }
+}
+
+// -------------------------------------------------- //
+
+/// Disjoint sets over hashable keys, added as they are first seen.
+#[derive(Debug, Clone)]
+pub struct UnionFind<K> {
+    sets: IndexUnionFind,
+    keys: Vec<K>,
+    indices: HashMap<K, usize>,
+}
+
+impl<K> Default for UnionFind<K> {
+    fn default() -> Self {
+        Self {
+            sets: IndexUnionFind::default(),
+            keys: Vec::new(),
+            indices: HashMap::new(),
+        }
+    }
+}
+
+impl<K: Clone + Eq + Hash> UnionFind<K> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    pub fn contains(&self, key: &K) -> bool {
+        self.indices.contains_key(key)
+    }
+
+    /// Adds `key` in its own set, if it is new.
+    ///
+    /// Returns the dense index used for `key`.
+    pub fn insert(&mut self, key: K) -> usize {
+        if let Some(idx) = self.indices.get(&key) {
+            return *idx;
+        }
+
+        let idx = self.sets.push();
+        self.keys.push(key.clone());
+        self.indices.insert(key, idx);
+        idx
+    }
+
+    /// Representative key of the set containing `key`.
+    pub fn find(&mut self, key: &K) -> Option<&K> {
+        let root = self.sets.find(*self.indices.get(key)?);
+        Some(&self.keys[root])
+    }
+
+    /// Merges the sets containing `lhs` and `rhs`, inserting either if new.
+    ///
+    /// Returns false if they were already in the same set.
+    pub fn union(&mut self, lhs: K, rhs: K) -> bool {
+        let lhs = self.insert(lhs);
+        let rhs = self.insert(rhs);
+        self.sets.union(lhs, rhs)
+    }
+
+    /// False if either key was never inserted.
+    pub fn connected(&mut self, lhs: &K, rhs: &K) -> bool {
+        match (self.indices.get(lhs), self.indices.get(rhs)) {
+            (Some(lhs), Some(rhs)) => self.sets.connected(*lhs, *rhs),
+            _ => false,
+        }
+    }
+
+    /// Number of keys in the set containing `key`.
+    pub fn size(&mut self, key: &K) -> Option<usize> {
+        Some(self.sets.size(*self.indices.get(key)?))
+    }
+
+    /// Number of disjoint sets.
+    pub fn component_count(&self) -> usize {
+        self.sets.component_count()
+    }
+
+    /// Size of every set.
+    pub fn component_sizes(&self) -> impl Iterator<Item = usize> + '_ {
+        self.sets.component_sizes()
+    }
+
+    /// Keys in every set.
+    pub fn components(&self) -> impl Iterator<Item = Vec<&K>> {
+        self.sets
+            .components()
+            .map(|component| component.into_iter().map(|idx| &self.keys[idx]).collect())
+    }
+}
+
+impl<K: Clone + Eq + Hash> FromIterator<K> for UnionFind<K> {
+    /// Each key in its own set.
+    fn from_iter<T: IntoIterator<Item = K>>(iter: T) -> Self {
+        let mut this = Self::new();
+        for key in iter {
+            this.insert(key);
+        }
+        this
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dense_unions() {
+        let mut sets = IndexUnionFind::new(6);
+
+        assert!(sets.union(0, 1));
+        assert!(sets.union(2, 3));
+        assert!(sets.union(1, 3));
+        assert!(!sets.union(0, 2));
+
+        assert_eq!(sets.component_count(), 3);
+        assert_eq!(sets.size(2), 4);
+        assert_eq!(sets.find(0), sets.find(3));
+
+        let mut sizes: Vec<_> = sets.component_sizes().collect();
+        sizes.sort_unstable();
+        assert_eq!(sizes, [1, 1, 4]);
+
+        let idx = sets.push();
+        assert_eq!(idx, 6);
+        assert_eq!(sets.component_count(), 4);
+    }
+
+    #[test]
+    fn keyed_components() {
+        let mut sets = UnionFind::new();
+        sets.union((0, 0), (0, 1));
+        sets.union((5, 5), (5, 6));
+        sets.insert((9, 9));
+
+        let mut components: Vec<Vec<_>> = sets
+            .components()
+            .map(|component| component.into_iter().copied().collect())
+            .collect();
+        components.sort_unstable();
+        assert_eq!(
+            components,
+            [vec![(0, 0), (0, 1)], vec![(5, 5), (5, 6)], vec![(9, 9)]]
+        );
+
+        assert!(!sets.connected(&(0, 0), &(5, 5)));
+        assert!(!sets.connected(&(0, 0), &(7, 7)));
+        assert_eq!(sets.size(&(7, 7)), None);
+        assert_eq!(sets.len(), 5);
+    }
+}